@@ -0,0 +1,53 @@
+//! Calendar arithmetic in whole months, where the length of the step depends
+//! on the date it starts from.
+
+use pyo3::prelude::*;
+use chrono::{DateTime, Datelike, Months, NaiveDate, NaiveDateTime, Utc};
+use crate::errors::ChronoError;
+use crate::local_result;
+use crate::timezone::AnyZone;
+
+/// Shifts `date` by `months` (negative to go back). A day past the end of
+/// the target month is clamped to its last day, or raises `OutOfRange` when
+/// `end_of_month` is `"raise"`.
+pub fn add_months(date: NaiveDate, months: i64, end_of_month: &str) -> PyResult<NaiveDate> {
+    if !matches!(end_of_month, "clamp" | "raise") {
+        return Err(ChronoError::InvalidArgument(
+            "end_of_month must be 'clamp' or 'raise'".to_string()
+        ).into());
+    }
+    let step = u32::try_from(months.unsigned_abs()).map(Months::new).map_err(|_| ChronoError::overflow())?;
+    let shifted = if months < 0 { date.checked_sub_months(step) } else { date.checked_add_months(step) }
+        .ok_or_else(ChronoError::overflow)?;
+    if end_of_month == "raise" && shifted.day() != date.day() {
+        return Err(ChronoError::OutOfRange(format!(
+            "{} has no day {} in {}-{:02}", date, date.day(), shifted.year(), shifted.month()
+        )).into());
+    }
+    Ok(shifted)
+}
+
+/// `years` as a number of months.
+pub fn years(years: i64) -> PyResult<i64> {
+    years.checked_mul(12).ok_or_else(|| ChronoError::overflow().into())
+}
+
+pub fn add_months_naive(dt: NaiveDateTime, months: i64, end_of_month: &str) -> PyResult<NaiveDateTime> {
+    Ok(NaiveDateTime::new(add_months(dt.date(), months, end_of_month)?, dt.time()))
+}
+
+/// Shifts the wall-clock time of `utc` in `zone` by `months` as `add_months`
+/// does, keeping the time of day, and resolves the result with the
+/// `ambiguous` and `nonexistent` policies of `NaiveDateTime.and_local_timezone`.
+/// Backs `add_months`, `sub_months` and `add_years` on every aware class.
+pub fn add_months_aware(
+    utc: &DateTime<Utc>,
+    zone: &AnyZone,
+    months: i64,
+    end_of_month: &str,
+    ambiguous: &str,
+    nonexistent: &str,
+) -> PyResult<DateTime<Utc>> {
+    let local = add_months_naive(zone.naive_local(utc), months, end_of_month)?;
+    local_result::resolve(&local, zone, ambiguous, nonexistent)
+}
@@ -1,10 +1,265 @@
+use std::collections::HashMap;
 use pyo3::prelude::*;
-use chrono::{DateTime, Utc, Local, FixedOffset, Datelike, Timelike};
+use pyo3::types::{PyDateTime as StdDateTime, PyTzInfoAccess};
+use chrono::{DateTime, Utc, Local, FixedOffset, Offset, Datelike, SubsecRound, Timelike, TimeZone};
 use crate::duration::PyDuration;
 use crate::naive::{PyNaiveDateTime, PyNaiveDate, PyNaiveTime};
-use crate::timezone::PyFixedOffset;
+use crate::timezone::{AnyZone, PyFixedOffset, PyLocal, PyPosixTz, PyTz, Zone};
 use crate::weekday::PyWeekday;
 use crate::errors::ChronoError;
+use crate::stdlib;
+use crate::pickle;
+use crate::calendar;
+use crate::human;
+use crate::locale;
+use crate::period::PyPeriod;
+use crate::rounding::{self, TruncateBy};
+
+/// The UTC instant of any aware datetime class, so that arithmetic and
+/// comparisons work across `DateTime`, `DateTimeUtc`, `DateTimeLocal`,
+/// `DateTimeFixed` and `DateTimeTz`.
+#[derive(Clone, Copy)]
+pub struct AnyDateTime(pub DateTime<Utc>);
+
+impl<'py> FromPyObject<'py> for AnyDateTime {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(dt) = ob.downcast::<PyDateTime>() {
+            Ok(AnyDateTime(dt.borrow().utc))
+        } else if let Ok(dt) = ob.downcast::<PyDateTimeUtc>() {
+            Ok(AnyDateTime(dt.borrow().inner))
+        } else if let Ok(dt) = ob.downcast::<PyDateTimeLocal>() {
+            Ok(AnyDateTime(dt.borrow().inner.to_utc()))
+        } else if let Ok(dt) = ob.downcast::<PyDateTimeFixed>() {
+            Ok(AnyDateTime(dt.borrow().inner.to_utc()))
+        } else if let Ok(dt) = ob.downcast::<PyDateTimeTz>() {
+            Ok(AnyDateTime(dt.borrow().inner.to_utc()))
+        } else {
+            Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "Expected DateTime, DateTimeUtc, DateTimeLocal, DateTimeFixed or DateTimeTz"
+            ))
+        }
+    }
+}
+
+/// What the methods shared by the aware classes need from each of them: its
+/// datetime, the zone that calendar arithmetic happens in, and a way back.
+trait Aware: Sized {
+    type Tz: TimeZone;
+
+    fn datetime(&self) -> DateTime<Self::Tz>;
+
+    fn wrap(inner: DateTime<Self::Tz>) -> Self;
+
+    fn zone(&self) -> AnyZone;
+
+    /// The same instant as `utc`, in this value's class and zone.
+    fn with_utc(&self, utc: DateTime<Utc>) -> Self {
+        Self::wrap(utc.with_timezone(&self.datetime().timezone()))
+    }
+
+    fn shift_months(&self, months: i64, end_of_month: &str, ambiguous: &str, nonexistent: &str) -> PyResult<Self> {
+        calendar::add_months_aware(&self.datetime().to_utc(), &self.zone(), months, end_of_month, ambiguous, nonexistent)
+            .map(|utc| self.with_utc(utc))
+    }
+
+    fn sub_period(&self, period: &PyPeriod) -> PyResult<Self> {
+        let period = period.checked_neg().ok_or_else(ChronoError::overflow)?;
+        period.apply_aware(&self.datetime().to_utc(), &self.zone(), "raise", "raise")
+            .map(|utc| self.with_utc(utc))
+    }
+}
+
+impl Aware for PyDateTime {
+    type Tz = Utc;
+
+    fn datetime(&self) -> DateTime<Utc> {
+        self.utc
+    }
+
+    fn wrap(utc: DateTime<Utc>) -> Self {
+        PyDateTime { utc }
+    }
+
+    fn zone(&self) -> AnyZone {
+        AnyZone::Utc
+    }
+}
+
+impl Aware for PyDateTimeUtc {
+    type Tz = Utc;
+
+    fn datetime(&self) -> DateTime<Utc> {
+        self.inner
+    }
+
+    fn wrap(inner: DateTime<Utc>) -> Self {
+        PyDateTimeUtc { inner }
+    }
+
+    fn zone(&self) -> AnyZone {
+        AnyZone::Utc
+    }
+}
+
+impl Aware for PyDateTimeLocal {
+    type Tz = Local;
+
+    fn datetime(&self) -> DateTime<Local> {
+        self.inner
+    }
+
+    fn wrap(inner: DateTime<Local>) -> Self {
+        PyDateTimeLocal { inner }
+    }
+
+    fn zone(&self) -> AnyZone {
+        AnyZone::Local
+    }
+}
+
+impl Aware for PyDateTimeFixed {
+    type Tz = FixedOffset;
+
+    fn datetime(&self) -> DateTime<FixedOffset> {
+        self.inner
+    }
+
+    fn wrap(inner: DateTime<FixedOffset>) -> Self {
+        PyDateTimeFixed { inner }
+    }
+
+    fn zone(&self) -> AnyZone {
+        AnyZone::Fixed(*self.inner.offset())
+    }
+}
+
+impl Aware for PyDateTimeTz {
+    type Tz = Zone;
+
+    fn datetime(&self) -> DateTime<Zone> {
+        self.inner.clone()
+    }
+
+    fn wrap(inner: DateTime<Zone>) -> Self {
+        PyDateTimeTz { inner }
+    }
+
+    fn zone(&self) -> AnyZone {
+        AnyZone::Zone(self.inner.timezone())
+    }
+}
+
+/// Wraps an aware class's `#[pymethods]` block and adds the methods that all
+/// five share, written once against [`Aware`].
+macro_rules! aware_pymethods {
+    ($(#[$attr:meta])* impl $class:ty { $($body:tt)* }) => {
+        pickle::immutable_pymethods! {
+            $(#[$attr])*
+            impl $class {
+                $($body)*
+
+                #[pyo3(signature = (months, end_of_month="clamp", ambiguous="raise", nonexistent="raise"))]
+                fn add_months(&self, months: i64, end_of_month: &str, ambiguous: &str, nonexistent: &str) -> PyResult<Self> {
+                    self.shift_months(months, end_of_month, ambiguous, nonexistent)
+                }
+
+                #[pyo3(signature = (months, end_of_month="clamp", ambiguous="raise", nonexistent="raise"))]
+                fn sub_months(&self, months: i64, end_of_month: &str, ambiguous: &str, nonexistent: &str) -> PyResult<Self> {
+                    self.shift_months(months.checked_neg().ok_or_else(ChronoError::overflow)?, end_of_month, ambiguous, nonexistent)
+                }
+
+                #[pyo3(signature = (years, end_of_month="clamp", ambiguous="raise", nonexistent="raise"))]
+                fn add_years(&self, years: i32, end_of_month: &str, ambiguous: &str, nonexistent: &str) -> PyResult<Self> {
+                    self.shift_months(calendar::years(years.into())?, end_of_month, ambiguous, nonexistent)
+                }
+
+                /// Describes this instant relative to `relative_to` (default: now), such
+                /// as "just now", "5 minutes ago", "in 3 weeks" or "yesterday at 14:00".
+                /// `granularity` is the smallest unit used; `thresholds` and `phrases`
+                /// override the cut-offs between units and the (English) wording.
+                #[pyo3(signature = (relative_to=None, granularity="second", thresholds=None, phrases=None))]
+                fn humanize(
+                    &self,
+                    relative_to: Option<AnyDateTime>,
+                    granularity: &str,
+                    thresholds: Option<HashMap<String, i64>>,
+                    phrases: Option<HashMap<String, String>>,
+                ) -> PyResult<String> {
+                    human::relative(&self.datetime().to_utc(), &self.zone(), relative_to.map(|dt| dt.0), granularity, thresholds, phrases)
+                }
+
+                fn __sub__(&self, rhs: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+                    let py = rhs.py();
+                    if let Ok(duration) = rhs.downcast::<PyDuration>() {
+                        Ok(self.datetime().checked_sub_signed(duration.borrow().inner)
+                            .map(Self::wrap)
+                            .ok_or_else(ChronoError::overflow)?
+                            .into_py(py))
+                    } else if let Ok(other) = rhs.extract::<AnyDateTime>() {
+                        let diff = self.datetime().to_utc().signed_duration_since(other.0);
+                        Ok(PyDuration {
+                            inner: diff,
+                        }.into_py(py))
+                    } else if let Ok(period) = rhs.downcast::<PyPeriod>() {
+                        Ok(self.sub_period(period.get())?.into_py(py))
+                    } else {
+                        Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                            "Can only subtract Duration, Period or an aware datetime"
+                        ))
+                    }
+                }
+
+                fn __eq__(&self, other: AnyDateTime) -> bool {
+                    self.datetime().to_utc() == other.0
+                }
+
+                fn __ne__(&self, other: AnyDateTime) -> bool {
+                    self.datetime().to_utc() != other.0
+                }
+
+                fn __lt__(&self, other: AnyDateTime) -> bool {
+                    self.datetime().to_utc() < other.0
+                }
+
+                fn __le__(&self, other: AnyDateTime) -> bool {
+                    self.datetime().to_utc() <= other.0
+                }
+
+                fn __gt__(&self, other: AnyDateTime) -> bool {
+                    self.datetime().to_utc() > other.0
+                }
+
+                fn __ge__(&self, other: AnyDateTime) -> bool {
+                    self.datetime().to_utc() >= other.0
+                }
+
+                fn __hash__(&self) -> u64 {
+                    self.datetime().timestamp() as u64
+                }
+
+                fn round(&self, duration: &PyDuration) -> PyResult<Self> {
+                    rounding::round(self.datetime(), duration.inner).map(Self::wrap)
+                }
+
+                fn ceil(&self, duration: &PyDuration) -> PyResult<Self> {
+                    rounding::ceil(self.datetime(), duration.inner).map(Self::wrap)
+                }
+
+                fn trunc(&self, by: TruncateBy) -> PyResult<Self> {
+                    rounding::trunc_aware(self.datetime(), &self.zone(), by).map(|utc| self.with_utc(utc))
+                }
+
+                fn round_subsecs(&self, digits: u16) -> Self {
+                    Self::wrap(self.datetime().round_subsecs(digits))
+                }
+
+                fn trunc_subsecs(&self, digits: u16) -> Self {
+                    Self::wrap(self.datetime().trunc_subsecs(digits))
+                }
+            }
+        }
+    };
+}
 
 #[pyclass(name = "DateTime")]
 #[derive(Clone)]
@@ -12,12 +267,32 @@ pub struct PyDateTime {
     pub utc: DateTime<Utc>,
 }
 
+aware_pymethods! {
 #[pymethods]
 impl PyDateTime {
     #[new]
-    #[pyo3(signature = (year, month, day, hour, minute, second, nano=None))]
-    fn new(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32, nano: Option<u32>) -> PyResult<Self> {
-        let naive = chrono::NaiveDate::from_ymd_opt(year, month, day)
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (year, month=None, day=None, hour=None, minute=None, second=None, nano=None))]
+    fn new(
+        year: &Bound<'_, PyAny>,
+        month: Option<u32>,
+        day: Option<u32>,
+        hour: Option<u32>,
+        minute: Option<u32>,
+        second: Option<u32>,
+        nano: Option<u32>,
+    ) -> PyResult<Self> {
+        if year.downcast::<StdDateTime>().is_ok() {
+            stdlib::check_no_fields("DateTime", &[month, day, hour, minute, second, nano])?;
+            return Self::from_pydatetime(year);
+        }
+        let (month, day) = (stdlib::required(month, "month")?, stdlib::required(day, "day")?);
+        let (hour, minute, second) = (
+            stdlib::required(hour, "hour")?,
+            stdlib::required(minute, "minute")?,
+            stdlib::required(second, "second")?,
+        );
+        let naive = chrono::NaiveDate::from_ymd_opt(year.extract()?, month, day)
             .ok_or_else(|| ChronoError::OutOfRange("Invalid date".to_string()))?
             .and_hms_nano_opt(hour, minute, second, nano.unwrap_or(0))
             .ok_or_else(|| ChronoError::OutOfRange("Invalid time".to_string()))?;
@@ -57,14 +332,14 @@ impl PyDateTime {
     fn parse_from_rfc3339(s: &str) -> PyResult<Self> {
         DateTime::parse_from_rfc3339(s)
             .map(|dt| PyDateTime { utc: dt.with_timezone(&Utc) })
-            .map_err(|e| ChronoError::from(e).into())
+            .map_err(|e| ChronoError::parse_rfc3339(e, s).into())
     }
 
     #[staticmethod]
     fn parse_from_rfc2822(s: &str) -> PyResult<Self> {
         DateTime::parse_from_rfc2822(s)
             .map(|dt| PyDateTime { utc: dt.with_timezone(&Utc) })
-            .map_err(|e| ChronoError::from(e).into())
+            .map_err(|e| ChronoError::parse_rfc2822(e, s).into())
     }
 
     fn to_utc(&self) -> PyDateTimeUtc {
@@ -79,6 +354,14 @@ impl PyDateTime {
         PyDateTimeFixed { inner: self.utc.with_timezone(&offset.inner) }
     }
 
+    fn to_tz(&self, tz: &PyTz) -> PyDateTimeTz {
+        PyDateTimeTz { inner: self.utc.with_timezone(&tz.inner) }
+    }
+
+    fn to_posix(&self, tz: &PyPosixTz) -> PyDateTimeTz {
+        PyDateTimeTz { inner: self.utc.with_timezone(&Zone::Posix(tz.inner.clone())) }
+    }
+
     fn naive_utc(&self) -> PyNaiveDateTime {
         PyNaiveDateTime { inner: self.utc.naive_utc() }
     }
@@ -159,71 +442,47 @@ impl PyDateTime {
         self.utc.to_rfc2822()
     }
 
-    fn format(&self, fmt: &str) -> String {
-        self.utc.format(fmt).to_string()
-    }
-
-    fn __add__(&self, rhs: &PyDuration) -> Self {
-        PyDateTime {
-            utc: self.utc + rhs.inner,
-        }
+    #[pyo3(signature = (fmt, locale=None))]
+    fn format(&self, fmt: &str, locale: Option<&str>) -> PyResult<String> {
+        locale::format_aware(&self.utc, fmt, locale)
     }
 
-    fn __sub__(&self, rhs: &Bound<'_, PyAny>) -> PyResult<PyObject> {
-        Python::with_gil(|py| {
-            if let Ok(duration) = rhs.downcast::<PyDuration>() {
-                Ok(PyDateTime {
-                    utc: self.utc - duration.borrow().inner,
-                }.into_py(py))
-            } else if let Ok(other) = rhs.downcast::<PyDateTime>() {
-                let diff = self.utc.signed_duration_since(other.borrow().utc);
-                Ok(PyDuration {
-                    inner: diff,
-                }.into_py(py))
-            } else {
-                Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
-                    "Can only subtract Duration or DateTime"
-                ))
-            }
-        })
+    fn __add__(&self, rhs: &PyDuration) -> PyResult<Self> {
+        self.checked_add(rhs).ok_or_else(|| ChronoError::overflow().into())
     }
 
-    fn __str__(&self) -> String {
-        self.utc.to_string()
-    }
-
-    fn __repr__(&self) -> String {
-        format!("DateTime({})", self.utc.to_rfc3339())
+    fn checked_add(&self, rhs: &PyDuration) -> Option<Self> {
+        self.utc.checked_add_signed(rhs.inner).map(|utc| PyDateTime { utc })
     }
 
-    fn __eq__(&self, other: &Self) -> bool {
-        self.utc == other.utc
+    fn checked_sub(&self, rhs: &PyDuration) -> Option<Self> {
+        self.utc.checked_sub_signed(rhs.inner).map(|utc| PyDateTime { utc })
     }
 
-    fn __ne__(&self, other: &Self) -> bool {
-        self.utc != other.utc
+    /// Converts to a tz-aware `datetime.datetime` in UTC.
+    fn to_pydatetime<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, StdDateTime>> {
+        stdlib::aware_to_py(py, &self.utc)
     }
 
-    fn __lt__(&self, other: &Self) -> bool {
-        self.utc < other.utc
-    }
-
-    fn __le__(&self, other: &Self) -> bool {
-        self.utc <= other.utc
+    /// Accepts an aware `datetime.datetime`, or a naive one taken as UTC.
+    #[staticmethod]
+    fn from_pydatetime(dt: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyDateTime { utc: stdlib::instant_from_py(dt)? })
     }
 
-    fn __gt__(&self, other: &Self) -> bool {
-        self.utc > other.utc
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "DateTime", pickle::instant_state(&self.utc))
     }
 
-    fn __ge__(&self, other: &Self) -> bool {
-        self.utc >= other.utc
+    fn __str__(&self) -> String {
+        self.utc.to_string()
     }
 
-    fn __hash__(&self) -> u64 {
-        self.utc.timestamp() as u64
+    fn __repr__(&self) -> String {
+        format!("DateTime({})", self.utc.to_rfc3339())
     }
 }
+}
 
 #[pyclass(name = "DateTimeUtc")]
 #[derive(Clone, Copy)]
@@ -231,12 +490,32 @@ pub struct PyDateTimeUtc {
     pub inner: DateTime<Utc>,
 }
 
+aware_pymethods! {
 #[pymethods]
 impl PyDateTimeUtc {
     #[new]
-    #[pyo3(signature = (year, month, day, hour, minute, second, nano=None))]
-    fn new(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32, nano: Option<u32>) -> PyResult<Self> {
-        let naive = chrono::NaiveDate::from_ymd_opt(year, month, day)
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (year, month=None, day=None, hour=None, minute=None, second=None, nano=None))]
+    fn new(
+        year: &Bound<'_, PyAny>,
+        month: Option<u32>,
+        day: Option<u32>,
+        hour: Option<u32>,
+        minute: Option<u32>,
+        second: Option<u32>,
+        nano: Option<u32>,
+    ) -> PyResult<Self> {
+        if year.downcast::<StdDateTime>().is_ok() {
+            stdlib::check_no_fields("DateTimeUtc", &[month, day, hour, minute, second, nano])?;
+            return Self::from_pydatetime(year);
+        }
+        let (month, day) = (stdlib::required(month, "month")?, stdlib::required(day, "day")?);
+        let (hour, minute, second) = (
+            stdlib::required(hour, "hour")?,
+            stdlib::required(minute, "minute")?,
+            stdlib::required(second, "second")?,
+        );
+        let naive = chrono::NaiveDate::from_ymd_opt(year.extract()?, month, day)
             .ok_or_else(|| ChronoError::OutOfRange("Invalid date".to_string()))?
             .and_hms_nano_opt(hour, minute, second, nano.unwrap_or(0))
             .ok_or_else(|| ChronoError::OutOfRange("Invalid time".to_string()))?;
@@ -302,12 +581,42 @@ impl PyDateTimeUtc {
         PyWeekday { inner: self.inner.weekday() }
     }
 
+    #[allow(clippy::wrong_self_convention)]
     fn to_rfc3339(&self) -> String {
         self.inner.to_rfc3339()
     }
 
-    fn format(&self, fmt: &str) -> String {
-        self.inner.format(fmt).to_string()
+    #[pyo3(signature = (fmt, locale=None))]
+    fn format(&self, fmt: &str, locale: Option<&str>) -> PyResult<String> {
+        locale::format_aware(&self.inner, fmt, locale)
+    }
+
+    fn __add__(&self, rhs: &PyDuration) -> PyResult<Self> {
+        self.checked_add(rhs).ok_or_else(|| ChronoError::overflow().into())
+    }
+
+    fn checked_add(&self, rhs: &PyDuration) -> Option<Self> {
+        self.inner.checked_add_signed(rhs.inner).map(|inner| PyDateTimeUtc { inner })
+    }
+
+    fn checked_sub(&self, rhs: &PyDuration) -> Option<Self> {
+        self.inner.checked_sub_signed(rhs.inner).map(|inner| PyDateTimeUtc { inner })
+    }
+
+    /// Converts to a tz-aware `datetime.datetime` with `timezone.utc`.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_pydatetime<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, StdDateTime>> {
+        stdlib::aware_to_py(py, &self.inner)
+    }
+
+    /// Accepts an aware `datetime.datetime`, or a naive one taken as UTC.
+    #[staticmethod]
+    fn from_pydatetime(dt: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyDateTimeUtc { inner: stdlib::instant_from_py(dt)? })
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "DateTimeUtc", pickle::instant_state(&self.inner))
     }
 
     fn __str__(&self) -> String {
@@ -318,6 +627,7 @@ impl PyDateTimeUtc {
         format!("DateTimeUtc({})", self.inner.to_rfc3339())
     }
 }
+}
 
 #[pyclass(name = "DateTimeLocal")]
 #[derive(Clone, Copy)]
@@ -325,6 +635,7 @@ pub struct PyDateTimeLocal {
     pub inner: DateTime<Local>,
 }
 
+aware_pymethods! {
 #[pymethods]
 impl PyDateTimeLocal {
     #[staticmethod]
@@ -364,12 +675,43 @@ impl PyDateTimeLocal {
         self.inner.second()
     }
 
+    #[allow(clippy::wrong_self_convention)]
     fn to_rfc3339(&self) -> String {
         self.inner.to_rfc3339()
     }
 
-    fn format(&self, fmt: &str) -> String {
-        self.inner.format(fmt).to_string()
+    #[pyo3(signature = (fmt, locale=None))]
+    fn format(&self, fmt: &str, locale: Option<&str>) -> PyResult<String> {
+        locale::format_aware(&self.inner, fmt, locale)
+    }
+
+    fn __add__(&self, rhs: &PyDuration) -> PyResult<Self> {
+        self.checked_add(rhs).ok_or_else(|| ChronoError::overflow().into())
+    }
+
+    fn checked_add(&self, rhs: &PyDuration) -> Option<Self> {
+        self.inner.checked_add_signed(rhs.inner).map(|inner| PyDateTimeLocal { inner })
+    }
+
+    fn checked_sub(&self, rhs: &PyDuration) -> Option<Self> {
+        self.inner.checked_sub_signed(rhs.inner).map(|inner| PyDateTimeLocal { inner })
+    }
+
+    /// Converts to a tz-aware `datetime.datetime` with a `Local` tzinfo.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_pydatetime<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, StdDateTime>> {
+        let tzinfo = Bound::new(py, PyLocal)?;
+        stdlib::zoned_to_py(py, &AnyZone::Local, &self.inner.to_utc(), tzinfo.as_any())
+    }
+
+    /// Accepts an aware `datetime.datetime`, or a naive one taken as UTC.
+    #[staticmethod]
+    fn from_pydatetime(dt: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyDateTimeLocal { inner: stdlib::instant_from_py(dt)?.with_timezone(&Local) })
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "DateTimeLocal", pickle::instant_state(&self.inner.to_utc()))
     }
 
     fn __str__(&self) -> String {
@@ -380,6 +722,7 @@ impl PyDateTimeLocal {
         format!("DateTimeLocal({})", self.inner.to_rfc3339())
     }
 }
+}
 
 #[pyclass(name = "DateTimeFixed")]
 #[derive(Clone, Copy)]
@@ -387,12 +730,38 @@ pub struct PyDateTimeFixed {
     pub inner: DateTime<FixedOffset>,
 }
 
+aware_pymethods! {
 #[pymethods]
 impl PyDateTimeFixed {
+    #[staticmethod]
+    fn parse_from_rfc3339(s: &str) -> PyResult<Self> {
+        DateTime::parse_from_rfc3339(s)
+            .map(|inner| PyDateTimeFixed { inner })
+            .map_err(|e| ChronoError::parse_rfc3339(e, s).into())
+    }
+
+    #[staticmethod]
+    fn parse_from_rfc2822(s: &str) -> PyResult<Self> {
+        DateTime::parse_from_rfc2822(s)
+            .map(|inner| PyDateTimeFixed { inner })
+            .map_err(|e| ChronoError::parse_rfc2822(e, s).into())
+    }
+
+    #[staticmethod]
+    fn parse_from_str(s: &str, fmt: &str) -> PyResult<Self> {
+        DateTime::parse_from_str(s, fmt)
+            .map(|inner| PyDateTimeFixed { inner })
+            .map_err(|e| ChronoError::parse(e, s, fmt).into())
+    }
+
     fn timestamp(&self) -> i64 {
         self.inner.timestamp()
     }
 
+    fn timestamp_millis(&self) -> i64 {
+        self.inner.timestamp_millis()
+    }
+
     fn year(&self) -> i32 {
         self.inner.year()
     }
@@ -417,16 +786,78 @@ impl PyDateTimeFixed {
         self.inner.second()
     }
 
+    fn nanosecond(&self) -> u32 {
+        self.inner.nanosecond()
+    }
+
+    fn weekday(&self) -> PyWeekday {
+        PyWeekday { inner: self.inner.weekday() }
+    }
+
+    fn offset(&self, py: Python) -> PyResult<Py<PyFixedOffset>> {
+        Py::new(py, PyFixedOffset { inner: *self.inner.offset() })
+    }
+
     fn offset_seconds(&self) -> i32 {
         self.inner.offset().local_minus_utc()
     }
 
+    #[allow(clippy::wrong_self_convention)]
+    fn to_utc(&self) -> PyDateTimeUtc {
+        PyDateTimeUtc { inner: self.inner.to_utc() }
+    }
+
+    fn naive_local(&self) -> PyNaiveDateTime {
+        PyNaiveDateTime { inner: self.inner.naive_local() }
+    }
+
+    fn naive_utc(&self) -> PyNaiveDateTime {
+        PyNaiveDateTime { inner: self.inner.naive_utc() }
+    }
+
+    #[allow(clippy::wrong_self_convention)]
     fn to_rfc3339(&self) -> String {
         self.inner.to_rfc3339()
     }
 
-    fn format(&self, fmt: &str) -> String {
-        self.inner.format(fmt).to_string()
+    #[allow(clippy::wrong_self_convention)]
+    fn to_rfc2822(&self) -> String {
+        self.inner.to_rfc2822()
+    }
+
+    #[pyo3(signature = (fmt, locale=None))]
+    fn format(&self, fmt: &str, locale: Option<&str>) -> PyResult<String> {
+        locale::format_aware(&self.inner, fmt, locale)
+    }
+
+    fn __add__(&self, rhs: &PyDuration) -> PyResult<Self> {
+        self.checked_add(rhs).ok_or_else(|| ChronoError::overflow().into())
+    }
+
+    fn checked_add(&self, rhs: &PyDuration) -> Option<Self> {
+        self.inner.checked_add_signed(rhs.inner).map(|inner| PyDateTimeFixed { inner })
+    }
+
+    fn checked_sub(&self, rhs: &PyDuration) -> Option<Self> {
+        self.inner.checked_sub_signed(rhs.inner).map(|inner| PyDateTimeFixed { inner })
+    }
+
+    /// Converts to a tz-aware `datetime.datetime` with a fixed `timezone`.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_pydatetime<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, StdDateTime>> {
+        stdlib::aware_to_py(py, &self.inner)
+    }
+
+    /// Accepts an aware `datetime.datetime`, keeping the offset its `tzinfo`
+    /// reports.
+    #[staticmethod]
+    fn from_pydatetime(dt: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyDateTimeFixed { inner: stdlib::aware_from_py(dt)? })
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        let (secs, nanos) = pickle::instant_state(&self.inner.to_utc());
+        pickle::reduce(py, "DateTimeFixed", (secs, nanos, self.inner.offset().local_minus_utc()))
     }
 
     fn __str__(&self) -> String {
@@ -437,3 +868,161 @@ impl PyDateTimeFixed {
         format!("DateTimeFixed({})", self.inner.to_rfc3339())
     }
 }
+}
+
+#[pyclass(name = "DateTimeTz")]
+#[derive(Clone)]
+pub struct PyDateTimeTz {
+    pub inner: DateTime<Zone>,
+}
+
+aware_pymethods! {
+#[pymethods]
+impl PyDateTimeTz {
+    #[staticmethod]
+    fn now(tz: &PyTz) -> Self {
+        PyDateTimeTz { inner: Utc::now().with_timezone(&tz.inner) }
+    }
+
+    fn timestamp(&self) -> i64 {
+        self.inner.timestamp()
+    }
+
+    fn timestamp_millis(&self) -> i64 {
+        self.inner.timestamp_millis()
+    }
+
+    fn year(&self) -> i32 {
+        self.inner.year()
+    }
+
+    fn month(&self) -> u32 {
+        self.inner.month()
+    }
+
+    fn day(&self) -> u32 {
+        self.inner.day()
+    }
+
+    fn hour(&self) -> u32 {
+        self.inner.hour()
+    }
+
+    fn minute(&self) -> u32 {
+        self.inner.minute()
+    }
+
+    fn second(&self) -> u32 {
+        self.inner.second()
+    }
+
+    fn nanosecond(&self) -> u32 {
+        self.inner.nanosecond()
+    }
+
+    fn weekday(&self) -> PyWeekday {
+        PyWeekday { inner: self.inner.weekday() }
+    }
+
+    fn timezone(&self, py: Python) -> PyResult<Py<PyTz>> {
+        Py::new(py, PyTz { inner: self.inner.timezone() })
+    }
+
+    fn offset_seconds(&self) -> i32 {
+        self.inner.offset().fix().local_minus_utc()
+    }
+
+    fn abbreviation(&self) -> String {
+        self.inner.offset().to_string()
+    }
+
+    fn is_dst(&self) -> bool {
+        self.inner.offset().is_dst()
+    }
+
+    fn to_utc(&self) -> PyDateTimeUtc {
+        PyDateTimeUtc { inner: self.inner.with_timezone(&Utc) }
+    }
+
+    fn to_tz(&self, tz: &PyTz) -> PyDateTimeTz {
+        PyDateTimeTz { inner: self.inner.with_timezone(&tz.inner) }
+    }
+
+    fn to_fixed(&self) -> PyDateTimeFixed {
+        PyDateTimeFixed { inner: self.inner.fixed_offset() }
+    }
+
+    fn naive_local(&self) -> PyNaiveDateTime {
+        PyNaiveDateTime { inner: self.inner.naive_local() }
+    }
+
+    fn naive_utc(&self) -> PyNaiveDateTime {
+        PyNaiveDateTime { inner: self.inner.naive_utc() }
+    }
+
+    fn to_rfc3339(&self) -> String {
+        self.inner.to_rfc3339()
+    }
+
+    #[pyo3(signature = (fmt, locale=None))]
+    fn format(&self, fmt: &str, locale: Option<&str>) -> PyResult<String> {
+        locale::format_aware(&self.inner, fmt, locale)
+    }
+
+    fn __add__(&self, rhs: &PyDuration) -> PyResult<Self> {
+        self.checked_add(rhs).ok_or_else(|| ChronoError::overflow().into())
+    }
+
+    fn checked_add(&self, rhs: &PyDuration) -> Option<Self> {
+        self.inner.clone().checked_add_signed(rhs.inner).map(|inner| PyDateTimeTz { inner })
+    }
+
+    fn checked_sub(&self, rhs: &PyDuration) -> Option<Self> {
+        self.inner.clone().checked_sub_signed(rhs.inner).map(|inner| PyDateTimeTz { inner })
+    }
+
+    /// Converts to a tz-aware `datetime.datetime` with this zone as a `Tz`
+    /// tzinfo.
+    fn to_pydatetime<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, StdDateTime>> {
+        let zone = self.inner.timezone();
+        let tzinfo = Bound::new(py, PyTz { inner: zone.clone() })?;
+        stdlib::zoned_to_py(py, &AnyZone::Zone(zone), &self.inner.to_utc(), tzinfo.as_any())
+    }
+
+    /// Accepts an aware `datetime.datetime`. Without `tz` its `tzinfo` must
+    /// be a `Tz`, `PosixTz`, `zoneinfo.ZoneInfo` or UTC so the zone can be
+    /// carried over.
+    #[staticmethod]
+    #[pyo3(signature = (dt, tz=None))]
+    fn from_pydatetime(dt: &Bound<'_, PyAny>, tz: Option<PyTz>) -> PyResult<Self> {
+        let fixed = stdlib::aware_from_py(dt)?;
+        let zone = match tz {
+            Some(tz) => tz.inner,
+            None => {
+                let tzinfo = dt.downcast::<StdDateTime>()?.get_tzinfo_bound();
+                match tzinfo.as_ref().and_then(stdlib::zone_from_tzinfo) {
+                    Some(AnyZone::Zone(zone)) => zone,
+                    Some(AnyZone::Utc) => Zone::Iana(chrono_tz::UTC),
+                    _ => return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                        "tzinfo has no matching time zone; pass tz explicitly"
+                    )),
+                }
+            }
+        };
+        Ok(PyDateTimeTz { inner: fixed.with_timezone(&zone) })
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        let (secs, nanos) = pickle::instant_state(&self.inner.to_utc());
+        pickle::reduce(py, "DateTimeTz", (secs, nanos, pickle::zone_state(py, &self.inner.timezone())))
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("DateTimeTz({}, {})", self.inner.to_rfc3339(), self.inner.timezone().name())
+    }
+}
+}
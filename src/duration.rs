@@ -1,5 +1,105 @@
 use pyo3::prelude::*;
+use pyo3::types::{PyDelta, PyFloat, PyLong};
 use chrono::Duration;
+use crate::stdlib;
+use crate::pickle;
+use crate::errors::ChronoError;
+use crate::iso8601;
+use crate::human;
+
+/// Builds a duration from a count of some unit, or `None` on overflow.
+type Unit = fn(i64) -> Option<Duration>;
+
+fn overflow() -> PyErr {
+    ChronoError::Overflow("Duration out of range".to_string()).into()
+}
+
+pub(crate) const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// Fraction digits beyond this cannot change the result by a nanosecond.
+const MAX_FRACTION_DIGITS: usize = 18;
+
+/// The error for years or months in a textual duration.
+pub(crate) const NO_FIXED_LENGTH: &str = "years and months have no fixed length; use Period";
+
+pub(crate) fn total_nanos(duration: &Duration) -> i128 {
+    i128::from(duration.num_seconds()) * NANOS_PER_SEC + i128::from(duration.subsec_nanos())
+}
+
+/// The duration `nanos` nanoseconds long, or `None` outside chrono's range.
+pub(crate) fn from_nanos(nanos: i128) -> Option<Duration> {
+    i64::try_from(nanos.div_euclid(NANOS_PER_SEC))
+        .ok()
+        .and_then(|secs| Duration::new(secs, nanos.rem_euclid(NANOS_PER_SEC) as u32))
+}
+
+/// The nanoseconds in the `digits` after a decimal mark, for a unit
+/// `unit_nanos` long, truncated.
+pub(crate) fn fraction_nanos(digits: &str, unit_nanos: i128) -> i128 {
+    let digits = &digits[..digits.len().min(MAX_FRACTION_DIGITS)];
+    let value: i128 = digits.parse().unwrap_or_default();
+    value * unit_nanos / 10i128.pow(digits.len() as u32)
+}
+
+/// Converts a Python int result back to a duration, with `OverflowError`
+/// rather than pyo3's conversion error when it does not fit.
+fn from_py_nanos(nanos: &Bound<'_, PyAny>) -> PyResult<Duration> {
+    from_nanos(nanos.extract().map_err(|_| overflow())?).ok_or_else(overflow)
+}
+
+/// `nanos * num / den` rounded half to even, the way `datetime.timedelta`
+/// rounds. The arithmetic uses Python ints, so a float's exact ratio works
+/// at any magnitude.
+fn scale(py: Python, nanos: i128, num: &Bound<'_, PyAny>, den: &Bound<'_, PyAny>) -> PyResult<Duration> {
+    let product = nanos.into_py(py).into_bound(py).mul(num)?;
+    let (quotient, remainder): (Bound<'_, PyAny>, Bound<'_, PyAny>) = product.divmod(den)?.extract()?;
+    let twice = remainder.mul(2)?;
+    let past_half = if den.gt(0)? { twice.gt(den)? } else { twice.lt(den)? };
+    let odd = quotient.rem(2)?.eq(1)?;
+    let quotient = if past_half || (twice.eq(den)? && odd) { quotient.add(1)? } else { quotient };
+    from_py_nanos(&quotient)
+}
+
+/// A `Duration` or a `datetime.timedelta`, so either works as an operand.
+pub struct AnyDuration(pub Duration);
+
+impl<'py> FromPyObject<'py> for AnyDuration {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(duration) = ob.downcast::<PyDuration>() {
+            Ok(AnyDuration(duration.borrow().inner))
+        } else if let Ok(delta) = ob.downcast::<PyDelta>() {
+            Ok(AnyDuration(delta.extract()?))
+        } else {
+            Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>("Expected Duration or datetime.timedelta"))
+        }
+    }
+}
+
+/// An int or float factor. Its exact ratio is taken only when it is used,
+/// so that NaN and infinities raise `ValueError` and `OverflowError` as with
+/// `timedelta`, instead of making the operator unsupported.
+struct Factor<'py>(Bound<'py, PyAny>);
+
+impl<'py> Factor<'py> {
+    /// The factor as `(numerator, denominator)`.
+    fn ratio(&self) -> PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)> {
+        if self.0.is_instance_of::<PyFloat>() {
+            self.0.call_method0("as_integer_ratio")?.extract()
+        } else {
+            Ok((self.0.clone(), 1i32.into_py(self.0.py()).into_bound(self.0.py())))
+        }
+    }
+}
+
+impl<'py> FromPyObject<'py> for Factor<'py> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if ob.is_instance_of::<PyLong>() || ob.is_instance_of::<PyFloat>() {
+            Ok(Factor(ob.clone()))
+        } else {
+            Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>("Expected int or float"))
+        }
+    }
+}
 
 #[pyclass(name = "Duration")]
 #[derive(Clone, Copy)]
@@ -7,11 +107,14 @@ pub struct PyDuration {
     pub inner: Duration,
 }
 
+pickle::immutable_pymethods! {
 #[pymethods]
 impl PyDuration {
     #[new]
-    #[pyo3(signature = (*, weeks=None, days=None, hours=None, minutes=None, seconds=None, milliseconds=None, microseconds=None, nanoseconds=None))]
-    fn new(
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value=None, *, weeks=None, days=None, hours=None, minutes=None, seconds=None, milliseconds=None, microseconds=None, nanoseconds=None))]
+    pub fn new(
+        value: Option<&Bound<'_, PyDelta>>,
         weeks: Option<i64>,
         days: Option<i64>,
         hours: Option<i64>,
@@ -21,31 +124,27 @@ impl PyDuration {
         microseconds: Option<i64>,
         nanoseconds: Option<i64>,
     ) -> PyResult<Self> {
-        let mut duration = Duration::zero();
-        
-        if let Some(w) = weeks {
-            duration = duration + Duration::weeks(w);
-        }
-        if let Some(d) = days {
-            duration = duration + Duration::days(d);
-        }
-        if let Some(h) = hours {
-            duration = duration + Duration::hours(h);
-        }
-        if let Some(m) = minutes {
-            duration = duration + Duration::minutes(m);
-        }
-        if let Some(s) = seconds {
-            duration = duration + Duration::seconds(s);
-        }
-        if let Some(ms) = milliseconds {
-            duration = duration + Duration::milliseconds(ms);
-        }
-        if let Some(us) = microseconds {
-            duration = duration + Duration::microseconds(us);
-        }
-        if let Some(ns) = nanoseconds {
-            duration = duration + Duration::nanoseconds(ns);
+        // A `datetime.timedelta` is the starting point; keyword units add on.
+        let mut duration = match value {
+            Some(delta) => delta.extract()?,
+            None => Duration::zero(),
+        };
+        let units: [(Option<i64>, Unit); 8] = [
+            (weeks, Duration::try_weeks),
+            (days, Duration::try_days),
+            (hours, Duration::try_hours),
+            (minutes, Duration::try_minutes),
+            (seconds, Duration::try_seconds),
+            (milliseconds, Duration::try_milliseconds),
+            (microseconds, |us| Some(Duration::microseconds(us))),
+            (nanoseconds, |ns| Some(Duration::nanoseconds(ns))),
+        ];
+        for (amount, unit) in units {
+            if let Some(amount) = amount {
+                duration = unit(amount)
+                    .and_then(|part| duration.checked_add(&part))
+                    .ok_or_else(overflow)?;
+            }
         }
 
         Ok(PyDuration { inner: duration })
@@ -67,33 +166,33 @@ impl PyDuration {
     }
 
     #[staticmethod]
-    fn weeks(weeks: i64) -> Self {
-        PyDuration { inner: Duration::weeks(weeks) }
+    fn weeks(weeks: i64) -> PyResult<Self> {
+        Duration::try_weeks(weeks).map(|inner| PyDuration { inner }).ok_or_else(overflow)
     }
 
     #[staticmethod]
-    fn days(days: i64) -> Self {
-        PyDuration { inner: Duration::days(days) }
+    fn days(days: i64) -> PyResult<Self> {
+        Duration::try_days(days).map(|inner| PyDuration { inner }).ok_or_else(overflow)
     }
 
     #[staticmethod]
-    fn hours(hours: i64) -> Self {
-        PyDuration { inner: Duration::hours(hours) }
+    fn hours(hours: i64) -> PyResult<Self> {
+        Duration::try_hours(hours).map(|inner| PyDuration { inner }).ok_or_else(overflow)
     }
 
     #[staticmethod]
-    fn minutes(minutes: i64) -> Self {
-        PyDuration { inner: Duration::minutes(minutes) }
+    fn minutes(minutes: i64) -> PyResult<Self> {
+        Duration::try_minutes(minutes).map(|inner| PyDuration { inner }).ok_or_else(overflow)
     }
 
     #[staticmethod]
-    fn seconds(seconds: i64) -> Self {
-        PyDuration { inner: Duration::seconds(seconds) }
+    fn seconds(seconds: i64) -> PyResult<Self> {
+        Duration::try_seconds(seconds).map(|inner| PyDuration { inner }).ok_or_else(overflow)
     }
 
     #[staticmethod]
-    fn milliseconds(milliseconds: i64) -> Self {
-        PyDuration { inner: Duration::milliseconds(milliseconds) }
+    fn milliseconds(milliseconds: i64) -> PyResult<Self> {
+        Duration::try_milliseconds(milliseconds).map(|inner| PyDuration { inner }).ok_or_else(overflow)
     }
 
     #[staticmethod]
@@ -146,25 +245,106 @@ impl PyDuration {
         PyDuration { inner: self.inner.abs() }
     }
 
+    #[allow(clippy::wrong_self_convention)]
     fn to_std(&self) -> PyResult<std::time::Duration> {
         self.inner.to_std()
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))
+            .map_err(|e| ChronoError::OutOfRange(e.to_string()).into())
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        self.inner.checked_add(&other.inner).map(|inner| PyDuration { inner })
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.inner.checked_sub(&other.inner).map(|inner| PyDuration { inner })
+    }
+
+    fn checked_mul(&self, rhs: i32) -> Option<Self> {
+        self.inner.checked_mul(rhs).map(|inner| PyDuration { inner })
     }
 
-    fn __add__(&self, other: &Self) -> Self {
-        PyDuration { inner: self.inner + other.inner }
+    /// Divides by `rhs`, returning `None` when `rhs` is zero.
+    fn checked_div(&self, rhs: i32) -> Option<Self> {
+        self.inner.checked_div(rhs).map(|inner| PyDuration { inner })
     }
 
-    fn __sub__(&self, other: &Self) -> Self {
-        PyDuration { inner: self.inner - other.inner }
+    /// The length in seconds, as `timedelta.total_seconds()` gives it.
+    fn total_seconds(&self, py: Python) -> PyResult<f64> {
+        total_nanos(&self.inner).into_py(py).into_bound(py).div(NANOS_PER_SEC)?.extract()
     }
 
-    fn __mul__(&self, rhs: i32) -> Self {
-        PyDuration { inner: self.inner * rhs }
+    fn __add__(&self, other: AnyDuration) -> PyResult<Self> {
+        self.inner.checked_add(&other.0).map(|inner| PyDuration { inner }).ok_or_else(overflow)
     }
 
-    fn __truediv__(&self, rhs: i32) -> Self {
-        PyDuration { inner: self.inner / rhs }
+    fn __radd__(&self, other: AnyDuration) -> PyResult<Self> {
+        self.__add__(other)
+    }
+
+    fn __sub__(&self, other: AnyDuration) -> PyResult<Self> {
+        self.inner.checked_sub(&other.0).map(|inner| PyDuration { inner }).ok_or_else(overflow)
+    }
+
+    fn __rsub__(&self, other: AnyDuration) -> PyResult<Self> {
+        other.0.checked_sub(&self.inner).map(|inner| PyDuration { inner }).ok_or_else(overflow)
+    }
+
+    /// Multiplies by an int or float. Results between two nanoseconds round
+    /// to the nearest, ties to even.
+    fn __mul__(&self, py: Python, factor: Factor<'_>) -> PyResult<Self> {
+        let (num, den) = factor.ratio()?;
+        scale(py, total_nanos(&self.inner), &num, &den).map(|inner| PyDuration { inner })
+    }
+
+    fn __rmul__(&self, py: Python, factor: Factor<'_>) -> PyResult<Self> {
+        self.__mul__(py, factor)
+    }
+
+    /// Divides by another duration, giving a float, or by an int or float,
+    /// giving a duration rounded like `__mul__`.
+    fn __truediv__(&self, py: Python, rhs: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let nanos = total_nanos(&self.inner);
+        if let Ok(other) = rhs.extract::<AnyDuration>() {
+            return Ok(nanos.into_py(py).into_bound(py).div(total_nanos(&other.0))?.unbind());
+        }
+        let Ok(divisor) = rhs.extract::<Factor<'_>>() else {
+            return Ok(py.NotImplemented());
+        };
+        let (num, den) = divisor.ratio()?;
+        Ok(PyDuration { inner: scale(py, nanos, &den, &num)? }.into_py(py))
+    }
+
+    /// Floor division by another duration, giving an int, or by an int,
+    /// giving a duration.
+    fn __floordiv__(&self, py: Python, rhs: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let nanos = total_nanos(&self.inner).into_py(py).into_bound(py);
+        if let Ok(other) = rhs.extract::<AnyDuration>() {
+            return Ok(nanos.floor_div(total_nanos(&other.0))?.unbind());
+        }
+        if !rhs.is_instance_of::<PyLong>() {
+            return Ok(py.NotImplemented());
+        }
+        Ok(PyDuration { inner: from_py_nanos(&nanos.floor_div(rhs)?)? }.into_py(py))
+    }
+
+    /// The remainder of floor division, with the sign of `rhs`.
+    fn __mod__(&self, py: Python, rhs: AnyDuration) -> PyResult<Self> {
+        let nanos = total_nanos(&self.inner).into_py(py).into_bound(py);
+        Ok(PyDuration { inner: from_py_nanos(&nanos.rem(total_nanos(&rhs.0))?)? })
+    }
+
+    fn __divmod__(&self, py: Python, rhs: AnyDuration) -> PyResult<(PyObject, Self)> {
+        let nanos = total_nanos(&self.inner).into_py(py).into_bound(py);
+        let (quotient, remainder): (PyObject, Bound<'_, PyAny>) = nanos.divmod(total_nanos(&rhs.0))?.extract()?;
+        Ok((quotient, PyDuration { inner: from_py_nanos(&remainder)? }))
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.inner.is_zero()
+    }
+
+    fn __pos__(&self) -> Self {
+        *self
     }
 
     fn __neg__(&self) -> Self {
@@ -199,15 +379,60 @@ impl PyDuration {
         self.inner >= other.inner
     }
 
+    /// Converts to a `datetime.timedelta`. Nanoseconds are truncated to
+    /// microseconds; durations beyond its range raise `OverflowError`.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_timedelta<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDelta>> {
+        stdlib::duration_to_py(py, &self.inner)
+    }
+
+    #[staticmethod]
+    fn from_timedelta(delta: &Bound<'_, PyDelta>) -> PyResult<Self> {
+        Ok(PyDuration { inner: delta.extract()? })
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "Duration", pickle::duration_state(&self.inner))
+    }
+
+    /// Parses an ISO 8601 duration such as `P3DT4H5M6.5S` or `-PT1.5S`.
+    #[staticmethod]
+    fn parse_iso8601(s: &str) -> PyResult<Self> {
+        Ok(PyDuration { inner: iso8601::parse_duration(s)? })
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn to_iso8601(&self) -> String {
+        iso8601::format_duration(&self.inner)
+    }
+
+    /// Writes the duration for people, e.g. "3 days, 4 hours" or, with
+    /// `style="short"`, "3d4h". `precision` is how many units to show,
+    /// counting down from the largest non-zero one; the last is rounded.
+    #[pyo3(signature = (precision=2, style="long"))]
+    fn humanize(&self, precision: usize, style: &str) -> PyResult<String> {
+        Ok(human::humanize(&self.inner, precision, style)?)
+    }
+
+    /// Parses strings such as "1h30m", "90 minutes", "2w 3d" or "1.5h".
+    #[staticmethod]
+    fn parse_human(s: &str) -> PyResult<Self> {
+        Ok(PyDuration { inner: human::parse_human(s)? })
+    }
+
     fn __str__(&self) -> String {
-        format!("{:?}", self.inner)
+        self.to_iso8601()
     }
 
     fn __repr__(&self) -> String {
-        format!("Duration(seconds={})", self.inner.num_seconds())
+        match self.inner.subsec_nanos() {
+            0 => format!("Duration(seconds={})", self.inner.num_seconds()),
+            nanos => format!("Duration(seconds={}, nanoseconds={})", self.inner.num_seconds(), nanos),
+        }
     }
 
     fn __hash__(&self) -> u64 {
         self.inner.num_nanoseconds().unwrap_or(0) as u64
     }
 }
+}
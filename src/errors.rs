@@ -1,21 +1,181 @@
 use pyo3::prelude::*;
-use pyo3::exceptions::PyValueError;
+use chrono::format::{self, Item, ParseErrorKind, Parsed, StrftimeItems};
+use crate::pickle;
+
+/// The Python exception classes. They all derive from `ValueError`, which is
+/// what every error used to be raised as.
+// pyo3 0.22's `create_exception!` expands to a `gil-refs` feature check in
+// this crate, which does not declare that feature.
+#[allow(unexpected_cfgs)]
+pub mod exceptions {
+    use pyo3::create_exception;
+    use pyo3::exceptions::PyValueError;
+
+    create_exception!(rusthonian_chrono, ChronoError, PyValueError, "Base class for all chrono errors.");
+    create_exception!(rusthonian_chrono, ParseError, ChronoError, "Input could not be parsed.");
+    create_exception!(rusthonian_chrono, InvalidFormatError, ChronoError, "A format string is invalid.");
+    create_exception!(rusthonian_chrono, OutOfRangeError, ChronoError, "A value is outside the supported range.");
+    create_exception!(rusthonian_chrono, InvalidTimezoneError, ChronoError, "A time zone is unknown or malformed.");
+}
+
+/// Mirrors chrono's `ParseErrorKind`; the `kind` of a raised `ParseError`.
+#[pyclass(name = "ParseErrorKind", eq, eq_int, frozen)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PyParseErrorKind {
+    OutOfRange,
+    Impossible,
+    NotEnough,
+    Invalid,
+    TooShort,
+    TooLong,
+    BadFormat,
+}
+
+impl From<ParseErrorKind> for PyParseErrorKind {
+    fn from(kind: ParseErrorKind) -> Self {
+        match kind {
+            ParseErrorKind::OutOfRange => PyParseErrorKind::OutOfRange,
+            ParseErrorKind::Impossible => PyParseErrorKind::Impossible,
+            ParseErrorKind::NotEnough => PyParseErrorKind::NotEnough,
+            ParseErrorKind::TooShort => PyParseErrorKind::TooShort,
+            ParseErrorKind::TooLong => PyParseErrorKind::TooLong,
+            ParseErrorKind::BadFormat => PyParseErrorKind::BadFormat,
+            _ => PyParseErrorKind::Invalid,
+        }
+    }
+}
+
+pickle::immutable_pymethods! {
+#[pymethods]
+impl PyParseErrorKind {
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "ParseErrorKind", (*self as u8,))
+    }
+
+}
+}
+
+/// A failed chrono parse, with the input and how far parsing got.
+#[derive(Debug)]
+pub struct ParseFailure {
+    pub kind: PyParseErrorKind,
+    pub message: String,
+    pub input: String,
+    /// `None` for the RFC 3339 / RFC 2822 parsers, which take no format.
+    pub format: Option<String>,
+    /// Byte offset into `input` where parsing stopped.
+    pub position: usize,
+}
 
 #[derive(Debug)]
 pub enum ChronoError {
     ParseError(String),
+    ParseFailure(Box<ParseFailure>),
     InvalidFormat(String),
     OutOfRange(String),
     InvalidTimezone(String),
+    /// An argument outside its allowed values, such as an unknown option name;
+    /// raised as the base `ChronoError`.
+    InvalidArgument(String),
+    /// Arithmetic left chrono's range; raised as `OverflowError`.
+    Overflow(String),
+}
+
+impl ChronoError {
+    /// Date or datetime arithmetic that went past the supported range.
+    pub fn overflow() -> Self {
+        ChronoError::Overflow("date value out of range".to_string())
+    }
+
+    /// A failed parse of `input` against a strftime-style `format`.
+    pub fn parse(error: chrono::ParseError, input: &str, format: &str) -> Self {
+        let position = parse_position(input, StrftimeItems::new(format));
+        Self::parse_failure(error, input, Some(format), position)
+    }
+
+    /// A failed RFC 3339 parse. chrono's parser reports no position, so it is
+    /// located against the canonical RFC 3339 layout.
+    pub fn parse_rfc3339(error: chrono::ParseError, input: &str) -> Self {
+        let position = parse_position(input, StrftimeItems::new(RFC3339_LAYOUT));
+        Self::parse_failure(error, input, None, position)
+    }
+
+    /// A failed RFC 2822 parse, located like [`ChronoError::parse_rfc3339`].
+    pub fn parse_rfc2822(error: chrono::ParseError, input: &str) -> Self {
+        let position = parse_position(input, StrftimeItems::new(RFC2822_LAYOUT));
+        Self::parse_failure(error, input, None, position)
+    }
+
+    /// A parse failure found by one of our own parsers rather than chrono's,
+    /// at byte offset `position` of `input`.
+    pub fn parse_at(kind: PyParseErrorKind, message: &str, input: &str, position: usize) -> Self {
+        ChronoError::ParseFailure(Box::new(ParseFailure {
+            kind,
+            message: message.to_string(),
+            input: input.to_string(),
+            format: None,
+            position,
+        }))
+    }
+
+    fn parse_failure(error: chrono::ParseError, input: &str, format: Option<&str>, position: usize) -> Self {
+        ChronoError::ParseFailure(Box::new(ParseFailure {
+            kind: error.kind().into(),
+            message: error.to_string(),
+            input: input.to_string(),
+            format: format.map(str::to_string),
+            position,
+        }))
+    }
+}
+
+const RFC3339_LAYOUT: &str = "%Y-%m-%dT%H:%M:%S%.f%#z";
+const RFC2822_LAYOUT: &str = "%a, %d %b %Y %H:%M:%S %z";
+
+/// Replays a parse one item at a time and returns the byte offset where it
+/// stopped. chrono parses items strictly left to right, so this is exactly
+/// where the full parse failed; errors found only once all input is read
+/// (impossible or missing fields) report the offset of any trailing input.
+fn parse_position<'a>(input: &str, items: impl Iterator<Item = Item<'a>>) -> usize {
+    let mut parsed = Parsed::new();
+    let mut rest = input;
+    for item in items {
+        match format::parse_and_remainder(&mut parsed, rest, std::iter::once(item)) {
+            Ok(remainder) => rest = remainder,
+            Err(_) => break,
+        }
+    }
+    input.len() - rest.len()
+}
+
+/// Builds a `ParseError` exception carrying `kind`, `input`, `format` and
+/// `position` attributes, which are `None` when unknown.
+fn parse_error(message: String, failure: Option<&ParseFailure>) -> PyErr {
+    let err = exceptions::ParseError::new_err(message);
+    Python::with_gil(|py| {
+        let value = err.value_bound(py);
+        // Plain attribute assignment on a fresh exception instance.
+        let _ = value.setattr("kind", failure.map(|f| f.kind.into_py(py)));
+        let _ = value.setattr("input", failure.map(|f| f.input.as_str()));
+        let _ = value.setattr("format", failure.and_then(|f| f.format.as_deref()));
+        let _ = value.setattr("position", failure.map(|f| f.position));
+    });
+    err
 }
 
 impl From<ChronoError> for PyErr {
     fn from(err: ChronoError) -> PyErr {
         match err {
-            ChronoError::ParseError(msg) => PyValueError::new_err(format!("Parse error: {}", msg)),
-            ChronoError::InvalidFormat(msg) => PyValueError::new_err(format!("Invalid format: {}", msg)),
-            ChronoError::OutOfRange(msg) => PyValueError::new_err(format!("Out of range: {}", msg)),
-            ChronoError::InvalidTimezone(msg) => PyValueError::new_err(format!("Invalid timezone: {}", msg)),
+            ChronoError::ParseError(msg) => parse_error(format!("Parse error: {}", msg), None),
+            ChronoError::ParseFailure(failure) => parse_error(
+                format!("Parse error: {} at position {}", failure.message, failure.position),
+                Some(&failure),
+            ),
+            ChronoError::InvalidFormat(msg) => exceptions::InvalidFormatError::new_err(format!("Invalid format: {}", msg)),
+            ChronoError::OutOfRange(msg) => exceptions::OutOfRangeError::new_err(format!("Out of range: {}", msg)),
+            ChronoError::InvalidTimezone(msg) => exceptions::InvalidTimezoneError::new_err(format!("Invalid timezone: {}", msg)),
+            ChronoError::InvalidArgument(msg) => exceptions::ChronoError::new_err(msg),
+            ChronoError::Overflow(msg) => pyo3::exceptions::PyOverflowError::new_err(msg),
         }
     }
 }
@@ -25,3 +185,14 @@ impl From<chrono::ParseError> for ChronoError {
         ChronoError::ParseError(err.to_string())
     }
 }
+
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add("ChronoError", py.get_type_bound::<exceptions::ChronoError>())?;
+    m.add("ParseError", py.get_type_bound::<exceptions::ParseError>())?;
+    m.add("InvalidFormatError", py.get_type_bound::<exceptions::InvalidFormatError>())?;
+    m.add("OutOfRangeError", py.get_type_bound::<exceptions::OutOfRangeError>())?;
+    m.add("InvalidTimezoneError", py.get_type_bound::<exceptions::InvalidTimezoneError>())?;
+    m.add_class::<PyParseErrorKind>()?;
+    Ok(())
+}
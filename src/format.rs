@@ -1,49 +1,136 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{Display, Write};
+use std::hash::{Hash, Hasher};
 use pyo3::prelude::*;
-use chrono::{DateTime, NaiveDateTime, NaiveDate, NaiveTime};
-use crate::datetime::PyDateTime;
+use pyo3::types::PyType;
+use chrono::format::{Fixed, Item, Numeric, Parsed, StrftimeItems};
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, NaiveDate, NaiveTime, Utc};
+use crate::datetime::{PyDateTime, PyDateTimeFixed, PyDateTimeLocal, PyDateTimeTz, PyDateTimeUtc};
 use crate::naive::{PyNaiveDateTime, PyNaiveDate, PyNaiveTime};
+use crate::timezone::Zone;
 use crate::errors::ChronoError;
+use crate::iso8601::{self, DateOrTime};
+use crate::pickle;
+
+/// The `%` specifiers of a strftime format string, each with its flags,
+/// width or precision (`%-d`, `%3f`, `%::z`, ...). A trailing lone `%` is
+/// yielded as is.
+pub fn specifiers(fmt: &str) -> impl Iterator<Item = &str> {
+    let mut rest = fmt;
+    std::iter::from_fn(move || {
+        let start = rest.find('%')?;
+        let spec = &rest[start..];
+        let modifiers = spec[1..]
+            .find(|c: char| !matches!(c, '-' | '_' | '0'..='9' | '#' | ':' | '.'))
+            .map_or(spec.len(), |i| i + 1);
+        let end = spec[modifiers..].chars().next().map_or(modifiers, |c| modifiers + c.len_utf8());
+        rest = &spec[end..];
+        Some(&spec[..end])
+    })
+}
+
+/// Checks that chrono understands every specifier in `fmt`.
+pub fn validate_format(fmt: &str) -> Result<(), ChronoError> {
+    if !StrftimeItems::new(fmt).any(|item| item == Item::Error) {
+        return Ok(());
+    }
+    let spec = specifiers(fmt)
+        .find(|spec| StrftimeItems::new(spec).any(|item| item == Item::Error))
+        .unwrap_or(fmt);
+    Err(ChronoError::InvalidFormat(format!("unknown specifier '{}' in '{}'", spec, fmt)))
+}
+
+/// Renders a chrono `format()` result, returning `None` where `to_string()`
+/// would panic.
+pub fn render(formatted: impl Display) -> Option<String> {
+    let mut out = String::new();
+    write!(out, "{}", formatted).ok().map(|_| out)
+}
+
+/// Formats a value with a strftime format string. `render_with` formats the
+/// value with a given string (normally `|f| render(value.format(f))`), and
+/// fails when a specifier needs a field the value lacks, such as `%H` on a
+/// date.
+pub fn strftime(fmt: &str, render_with: impl Fn(&str) -> Option<String>) -> PyResult<String> {
+    validate_format(fmt)?;
+    render_with(fmt).ok_or_else(|| missing_field(fmt, render_with).into())
+}
+
+/// The error for a format string that `render_with` could not render,
+/// naming the first specifier the value has no field for.
+fn missing_field(fmt: &str, render_with: impl Fn(&str) -> Option<String>) -> ChronoError {
+    let spec = specifiers(fmt).find(|spec| render_with(spec).is_none()).unwrap_or(fmt);
+    ChronoError::InvalidFormat(format!("'{}' in '{}' needs a field this value does not have", spec, fmt))
+}
 
 #[pyfunction]
 pub fn parse_datetime(s: &str, fmt: &str) -> PyResult<PyNaiveDateTime> {
     NaiveDateTime::parse_from_str(s, fmt)
         .map(|inner| PyNaiveDateTime { inner })
-        .map_err(|e| ChronoError::from(e).into())
+        .map_err(|e| ChronoError::parse(e, s, fmt).into())
 }
 
 #[pyfunction]
 pub fn parse_date(s: &str, fmt: &str) -> PyResult<PyNaiveDate> {
     NaiveDate::parse_from_str(s, fmt)
         .map(|inner| PyNaiveDate { inner })
-        .map_err(|e| ChronoError::from(e).into())
+        .map_err(|e| ChronoError::parse(e, s, fmt).into())
 }
 
 #[pyfunction]
 pub fn parse_time(s: &str, fmt: &str) -> PyResult<PyNaiveTime> {
     NaiveTime::parse_from_str(s, fmt)
         .map(|inner| PyNaiveTime { inner })
-        .map_err(|e| ChronoError::from(e).into())
+        .map_err(|e| ChronoError::parse(e, s, fmt).into())
 }
 
 #[pyfunction]
 pub fn parse_from_str(s: &str, fmt: &str) -> PyResult<PyNaiveDateTime> {
     NaiveDateTime::parse_from_str(s, fmt)
         .map(|inner| PyNaiveDateTime { inner })
-        .map_err(|e| ChronoError::from(e).into())
+        .map_err(|e| ChronoError::parse(e, s, fmt).into())
 }
 
 #[pyfunction]
 pub fn parse_from_rfc3339(s: &str) -> PyResult<PyDateTime> {
     DateTime::parse_from_rfc3339(s)
         .map(|dt| PyDateTime { utc: dt.with_timezone(&chrono::Utc) })
-        .map_err(|e| ChronoError::from(e).into())
+        .map_err(|e| ChronoError::parse_rfc3339(e, s).into())
+}
+
+/// Parses any common ISO 8601 date or date and time (see
+/// `iso8601::parse_datetime`), returning a `NaiveDate`, a `NaiveDateTime`, a
+/// `DateTimeUtc` for `Z` or a `DateTimeFixed` for a numeric offset.
+#[pyfunction]
+pub fn parse_iso8601(py: Python, s: &str) -> PyResult<PyObject> {
+    Ok(match iso8601::parse_datetime(s)? {
+        DateOrTime::Date(inner) => PyNaiveDate { inner }.into_py(py),
+        DateOrTime::Naive(inner) => PyNaiveDateTime { inner }.into_py(py),
+        DateOrTime::Utc(inner) => PyDateTimeUtc { inner }.into_py(py),
+        DateOrTime::Fixed(inner) => PyDateTimeFixed { inner }.into_py(py),
+    })
 }
 
 #[pyfunction]
 pub fn parse_from_rfc2822(s: &str) -> PyResult<PyDateTime> {
     DateTime::parse_from_rfc2822(s)
         .map(|dt| PyDateTime { utc: dt.with_timezone(&chrono::Utc) })
-        .map_err(|e| ChronoError::from(e).into())
+        .map_err(|e| ChronoError::parse_rfc2822(e, s).into())
+}
+
+#[pyfunction]
+pub fn parse_from_rfc3339_fixed(s: &str) -> PyResult<PyDateTimeFixed> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|inner| PyDateTimeFixed { inner })
+        .map_err(|e| ChronoError::parse_rfc3339(e, s).into())
+}
+
+#[pyfunction]
+pub fn parse_from_rfc2822_fixed(s: &str) -> PyResult<PyDateTimeFixed> {
+    DateTime::parse_from_rfc2822(s)
+        .map(|inner| PyDateTimeFixed { inner })
+        .map_err(|e| ChronoError::parse_rfc2822(e, s).into())
 }
 
 #[pyfunction]
@@ -55,3 +142,227 @@ pub fn format_rfc3339(dt: &PyDateTime) -> String {
 pub fn format_rfc2822(dt: &PyDateTime) -> String {
     dt.utc.to_rfc2822()
 }
+
+/// Any value a `Formatter` can format.
+enum Formattable {
+    Utc(DateTime<Utc>),
+    Local(DateTime<Local>),
+    Fixed(DateTime<FixedOffset>),
+    Tz(DateTime<Zone>),
+    NaiveDateTime(NaiveDateTime),
+    NaiveDate(NaiveDate),
+    NaiveTime(NaiveTime),
+}
+
+impl Formattable {
+    fn render<'a, I, B>(&self, items: I) -> Option<String>
+    where
+        I: Iterator<Item = B> + Clone,
+        B: Borrow<Item<'a>>,
+    {
+        match self {
+            Formattable::Utc(dt) => render(dt.format_with_items(items)),
+            Formattable::Local(dt) => render(dt.format_with_items(items)),
+            Formattable::Fixed(dt) => render(dt.format_with_items(items)),
+            Formattable::Tz(dt) => render(dt.format_with_items(items)),
+            Formattable::NaiveDateTime(dt) => render(dt.format_with_items(items)),
+            Formattable::NaiveDate(date) => render(date.format_with_items(items)),
+            Formattable::NaiveTime(time) => render(time.format_with_items(items)),
+        }
+    }
+}
+
+impl<'py> FromPyObject<'py> for Formattable {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(dt) = ob.downcast::<PyDateTime>() {
+            Ok(Formattable::Utc(dt.borrow().utc))
+        } else if let Ok(dt) = ob.downcast::<PyDateTimeUtc>() {
+            Ok(Formattable::Utc(dt.borrow().inner))
+        } else if let Ok(dt) = ob.downcast::<PyDateTimeLocal>() {
+            Ok(Formattable::Local(dt.borrow().inner))
+        } else if let Ok(dt) = ob.downcast::<PyDateTimeFixed>() {
+            Ok(Formattable::Fixed(dt.borrow().inner))
+        } else if let Ok(dt) = ob.downcast::<PyDateTimeTz>() {
+            Ok(Formattable::Tz(dt.borrow().inner.clone()))
+        } else if let Ok(dt) = ob.downcast::<PyNaiveDateTime>() {
+            Ok(Formattable::NaiveDateTime(dt.borrow().inner))
+        } else if let Ok(date) = ob.downcast::<PyNaiveDate>() {
+            Ok(Formattable::NaiveDate(date.borrow().inner))
+        } else if let Ok(time) = ob.downcast::<PyNaiveTime>() {
+            Ok(Formattable::NaiveTime(time.borrow().inner))
+        } else {
+            Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "Expected a datetime, NaiveDateTime, NaiveDate or NaiveTime"
+            ))
+        }
+    }
+}
+
+/// The class `Formatter.parse` builds.
+#[derive(Clone, Copy, PartialEq)]
+enum ParseTarget {
+    DateTime,
+    DateTimeUtc,
+    DateTimeLocal,
+    DateTimeFixed,
+    NaiveDateTime,
+    NaiveDate,
+    NaiveTime,
+}
+
+impl ParseTarget {
+    fn from_class(cls: &Bound<'_, PyType>) -> PyResult<Self> {
+        let py = cls.py();
+        let targets = [
+            (py.get_type_bound::<PyDateTime>(), ParseTarget::DateTime),
+            (py.get_type_bound::<PyDateTimeUtc>(), ParseTarget::DateTimeUtc),
+            (py.get_type_bound::<PyDateTimeLocal>(), ParseTarget::DateTimeLocal),
+            (py.get_type_bound::<PyDateTimeFixed>(), ParseTarget::DateTimeFixed),
+            (py.get_type_bound::<PyNaiveDateTime>(), ParseTarget::NaiveDateTime),
+            (py.get_type_bound::<PyNaiveDate>(), ParseTarget::NaiveDate),
+            (py.get_type_bound::<PyNaiveTime>(), ParseTarget::NaiveTime),
+        ];
+        targets.into_iter()
+            .find(|(class, _)| cls.is(class))
+            .map(|(_, target)| target)
+            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+                "Cannot parse into {}; parse a DateTimeFixed and convert it instead",
+                cls.name().map_or_else(|_| "this class".to_string(), |name| name.to_string())
+            )))
+    }
+
+    /// The most specific class the fields in `items` can fill: a
+    /// `DateTimeFixed` when there is a date, a time and an offset, otherwise
+    /// whichever naive type covers the fields present.
+    fn infer(items: &[Item<'_>]) -> Self {
+        let permissive_offset = StrftimeItems::new("%#z").next();
+        let (mut date, mut time, mut offset) = (false, false, false);
+        for item in items {
+            match item {
+                Item::Numeric(Numeric::Timestamp, _) => (date, time) = (true, true),
+                Item::Numeric(
+                    Numeric::Year | Numeric::YearDiv100 | Numeric::YearMod100 | Numeric::IsoYear
+                    | Numeric::IsoYearDiv100 | Numeric::IsoYearMod100 | Numeric::Month | Numeric::Day
+                    | Numeric::WeekFromSun | Numeric::WeekFromMon | Numeric::IsoWeek | Numeric::Ordinal,
+                    _,
+                )
+                | Item::Fixed(Fixed::ShortMonthName | Fixed::LongMonthName) => date = true,
+                Item::Numeric(Numeric::Hour | Numeric::Hour12 | Numeric::Minute | Numeric::Second, _) => time = true,
+                Item::Fixed(
+                    Fixed::TimezoneOffset | Fixed::TimezoneOffsetColon | Fixed::TimezoneOffsetDoubleColon
+                    | Fixed::TimezoneOffsetTripleColon | Fixed::TimezoneOffsetColonZ | Fixed::TimezoneOffsetZ,
+                ) => offset = true,
+                Item::Fixed(Fixed::RFC2822 | Fixed::RFC3339) => (date, time, offset) = (true, true, true),
+                item if Some(item) == permissive_offset.as_ref() => offset = true,
+                _ => {}
+            }
+        }
+        match (date, time, offset) {
+            (true, true, true) => ParseTarget::DateTimeFixed,
+            (true, false, _) => ParseTarget::NaiveDate,
+            (false, true, _) => ParseTarget::NaiveTime,
+            _ => ParseTarget::NaiveDateTime,
+        }
+    }
+}
+
+/// A strftime format string compiled once, for formatting or parsing many
+/// values with the same pattern.
+#[pyclass(name = "Formatter", frozen)]
+pub struct PyFormatter {
+    fmt: String,
+    items: Vec<Item<'static>>,
+    target: ParseTarget,
+}
+
+impl PyFormatter {
+    fn format_value(&self, value: &Formattable) -> Result<String, ChronoError> {
+        value.render(self.items.iter())
+            .ok_or_else(|| missing_field(&self.fmt, |f| value.render(StrftimeItems::new(f))))
+    }
+
+    fn parse_value(&self, py: Python, s: &str, target: ParseTarget) -> PyResult<PyObject> {
+        let error = |e| PyErr::from(ChronoError::parse(e, s, &self.fmt));
+        let mut parsed = Parsed::new();
+        chrono::format::parse(&mut parsed, s, self.items.iter()).map_err(error)?;
+        Ok(match target {
+            ParseTarget::DateTime => PyDateTime { utc: parsed.to_datetime().map_err(error)?.to_utc() }.into_py(py),
+            ParseTarget::DateTimeUtc => PyDateTimeUtc { inner: parsed.to_datetime().map_err(error)?.to_utc() }.into_py(py),
+            ParseTarget::DateTimeLocal => {
+                PyDateTimeLocal { inner: parsed.to_datetime().map_err(error)?.with_timezone(&Local) }.into_py(py)
+            }
+            ParseTarget::DateTimeFixed => PyDateTimeFixed { inner: parsed.to_datetime().map_err(error)? }.into_py(py),
+            ParseTarget::NaiveDateTime => {
+                PyNaiveDateTime { inner: parsed.to_naive_datetime_with_offset(0).map_err(error)? }.into_py(py)
+            }
+            ParseTarget::NaiveDate => PyNaiveDate { inner: parsed.to_naive_date().map_err(error)? }.into_py(py),
+            ParseTarget::NaiveTime => PyNaiveTime { inner: parsed.to_naive_time().map_err(error)? }.into_py(py),
+        })
+    }
+
+    fn target(&self, cls: Option<&Bound<'_, PyType>>) -> PyResult<ParseTarget> {
+        cls.map_or(Ok(self.target), ParseTarget::from_class)
+    }
+}
+
+pickle::immutable_pymethods! {
+#[pymethods]
+impl PyFormatter {
+    #[new]
+    pub fn new(fmt: &str) -> PyResult<Self> {
+        validate_format(fmt)?;
+        let items = StrftimeItems::new(fmt)
+            .parse_to_owned()
+            .map_err(|_| ChronoError::InvalidFormat(format!("invalid format string '{}'", fmt)))?;
+        let target = ParseTarget::infer(&items);
+        Ok(PyFormatter { fmt: fmt.to_string(), items, target })
+    }
+
+    fn pattern(&self) -> &str {
+        &self.fmt
+    }
+
+    fn format(&self, value: Formattable) -> PyResult<String> {
+        Ok(self.format_value(&value)?)
+    }
+
+    fn format_many(&self, values: Vec<Formattable>) -> PyResult<Vec<String>> {
+        Ok(values.iter().map(|value| self.format_value(value)).collect::<Result<_, _>>()?)
+    }
+
+    /// Parses `s` into `cls`, or by default into the most specific class the
+    /// pattern's fields allow.
+    #[pyo3(signature = (s, cls=None))]
+    fn parse(&self, py: Python, s: &str, cls: Option<&Bound<'_, PyType>>) -> PyResult<PyObject> {
+        self.parse_value(py, s, self.target(cls)?)
+    }
+
+    #[pyo3(signature = (strings, cls=None))]
+    fn parse_many(&self, py: Python, strings: Vec<String>, cls: Option<&Bound<'_, PyType>>) -> PyResult<Vec<PyObject>> {
+        let target = self.target(cls)?;
+        strings.iter().map(|s| self.parse_value(py, s, target)).collect()
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "Formatter", (self.fmt.as_str(),))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Formatter({:?})", self.fmt)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.fmt == other.fmt
+    }
+
+    fn __ne__(&self, other: &Self) -> bool {
+        self.fmt != other.fmt
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.fmt.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+}
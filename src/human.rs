@@ -0,0 +1,345 @@
+//! Human-readable durations: "3 days, 4 hours" / "3d4h" out, and
+//! humantime / Go `time.ParseDuration` style strings such as "1h30m",
+//! "90 minutes" or "2w 3d" in.
+
+use std::collections::HashMap;
+use pyo3::prelude::*;
+use chrono::{DateTime, Duration, Utc};
+use crate::duration::{self, NANOS_PER_SEC};
+use crate::errors::{ChronoError, PyParseErrorKind};
+use crate::format;
+use crate::timezone::AnyZone;
+
+/// The units `humanize` writes, largest first: nanoseconds per unit, long
+/// name and short suffix.
+const UNITS: [(i128, &str, &str); 7] = [
+    (86_400 * NANOS_PER_SEC, "day", "d"),
+    (3_600 * NANOS_PER_SEC, "hour", "h"),
+    (60 * NANOS_PER_SEC, "minute", "m"),
+    (NANOS_PER_SEC, "second", "s"),
+    (1_000_000, "millisecond", "ms"),
+    (1_000, "microsecond", "us"),
+    (1, "nanosecond", "ns"),
+];
+
+/// The unit names `parse_human` accepts, matched case-insensitively, with
+/// their length in nanoseconds. Zero marks years and months, which are
+/// rejected since they have no fixed length.
+const UNIT_NAMES: [(&[&str], i128); 10] = [
+    (&["ns", "nsec", "nsecs", "nanosecond", "nanoseconds"], 1),
+    (&["us", "µs", "usec", "usecs", "microsecond", "microseconds"], 1_000),
+    (&["ms", "msec", "msecs", "millisecond", "milliseconds"], 1_000_000),
+    (&["s", "sec", "secs", "second", "seconds"], NANOS_PER_SEC),
+    (&["m", "min", "mins", "minute", "minutes"], 60 * NANOS_PER_SEC),
+    (&["h", "hr", "hrs", "hour", "hours"], 3_600 * NANOS_PER_SEC),
+    (&["d", "day", "days"], 86_400 * NANOS_PER_SEC),
+    (&["w", "wk", "wks", "week", "weeks"], 7 * 86_400 * NANOS_PER_SEC),
+    (&["mo", "month", "months"], 0),
+    (&["y", "yr", "yrs", "year", "years"], 0),
+];
+
+/// Writes `duration` using its `precision` largest units, starting from the
+/// largest non-zero one and rounding the last unit shown (halves away from
+/// zero). `style` is `"long"` ("3 days, 4 hours") or `"short"` ("3d4h").
+pub fn humanize(duration: &Duration, precision: usize, style: &str) -> Result<String, ChronoError> {
+    if precision == 0 {
+        return Err(ChronoError::InvalidArgument("precision must be at least 1".to_string()));
+    }
+    let long = match style {
+        "long" => true,
+        "short" => false,
+        _ => return Err(ChronoError::InvalidArgument("style must be 'long' or 'short'".to_string())),
+    };
+
+    let nanos = duration::total_nanos(duration);
+    let magnitude = nanos.unsigned_abs();
+    let first = UNITS.iter()
+        .position(|&(size, _, _)| magnitude >= size as u128)
+        .unwrap_or(UNITS.len() - 1);
+    let last = first.saturating_add(precision - 1).min(UNITS.len() - 1);
+    let step = UNITS[last].0 as u128;
+    let mut rest = (magnitude + step / 2) / step * step;
+
+    let mut parts = Vec::new();
+    for &(size, name, suffix) in &UNITS[..=last] {
+        let count = rest / size as u128;
+        rest %= size as u128;
+        if count == 0 {
+            continue;
+        }
+        parts.push(match (long, count) {
+            (true, 1) => format!("1 {}", name),
+            (true, _) => format!("{} {}s", count, name),
+            (false, _) => format!("{}{}", count, suffix),
+        });
+    }
+    if parts.is_empty() {
+        return Ok(if long { "0 seconds" } else { "0s" }.to_string());
+    }
+
+    let sign = if nanos < 0 { "-" } else { "" };
+    Ok(format!("{}{}", sign, parts.join(if long { ", " } else { "" })))
+}
+
+/// Parses a sequence of `<number><unit>` components, optionally separated by
+/// spaces, commas or "and", with an optional leading sign. Numbers may be
+/// fractional ("1.5h").
+pub fn parse_human(input: &str) -> Result<Duration, ChronoError> {
+    let fail = |kind, message: &str, position| ChronoError::parse_at(kind, message, input, position);
+    let bytes = input.as_bytes();
+    let skip_separators = |mut pos: usize| {
+        loop {
+            while pos < bytes.len() && (bytes[pos].is_ascii_whitespace() || bytes[pos] == b',') {
+                pos += 1;
+            }
+            let rest = &input[pos..];
+            let word = rest.get(..3).is_some_and(|word| word.eq_ignore_ascii_case("and"));
+            if word && rest.as_bytes().get(3).is_some_and(u8::is_ascii_whitespace) {
+                pos += 3;
+            } else {
+                return pos;
+            }
+        }
+    };
+
+    let mut pos = skip_separators(0);
+    let negative = bytes.get(pos) == Some(&b'-');
+    if matches!(bytes.get(pos), Some(b'-' | b'+')) {
+        pos = skip_separators(pos + 1);
+    }
+
+    let mut total: i128 = 0;
+    let mut components = 0;
+    while pos < bytes.len() {
+        let start = pos;
+        let whole_end = start + bytes[start..].iter().take_while(|b| b.is_ascii_digit()).count();
+        let mut end = whole_end;
+        let mut fraction = "";
+        if bytes.get(end) == Some(&b'.') {
+            let digits = bytes[end + 1..].iter().take_while(|b| b.is_ascii_digit()).count();
+            fraction = &input[end + 1..end + 1 + digits];
+            end += 1 + digits;
+        }
+        if whole_end == start && fraction.is_empty() {
+            return Err(fail(PyParseErrorKind::Invalid, "expected a number", start));
+        }
+
+        let unit_start = end + bytes[end..].iter().take_while(|b| **b == b' ').count();
+        let unit_len = input[unit_start..]
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(input.len() - unit_start);
+        let unit = &input[unit_start..unit_start + unit_len];
+        if unit.is_empty() {
+            return Err(fail(PyParseErrorKind::TooShort, "expected a unit", unit_start));
+        }
+        let Some(&(_, size)) = UNIT_NAMES.iter()
+            .find(|(names, _)| names.iter().any(|name| name.eq_ignore_ascii_case(unit)))
+        else {
+            return Err(fail(PyParseErrorKind::Invalid, "unknown unit", unit_start));
+        };
+        if size == 0 {
+            return Err(fail(
+                PyParseErrorKind::Invalid,
+                duration::NO_FIXED_LENGTH,
+                unit_start,
+            ));
+        }
+
+        let out_of_range = || fail(PyParseErrorKind::OutOfRange, "duration is out of range", start);
+        let whole: i128 = match &input[start..whole_end] {
+            "" => 0,
+            digits => digits.parse().map_err(|_| out_of_range())?,
+        };
+        let mut nanos = whole.checked_mul(size).ok_or_else(out_of_range)?;
+        nanos += duration::fraction_nanos(fraction, size);
+        total = total.checked_add(nanos).ok_or_else(out_of_range)?;
+        components += 1;
+        pos = skip_separators(unit_start + unit_len);
+    }
+    if components == 0 {
+        return Err(fail(PyParseErrorKind::TooShort, "expected at least one component", pos));
+    }
+
+    let total = if negative { -total } else { total };
+    duration::from_nanos(total).ok_or_else(|| fail(PyParseErrorKind::OutOfRange, "duration is out of range", 0))
+}
+
+/// The units of a relative time, smallest first: name and approximate
+/// length in seconds (months and years are Gregorian averages).
+const RELATIVE_UNITS: [(&str, f64); 7] = [
+    ("second", 1.0),
+    ("minute", 60.0),
+    ("hour", 3_600.0),
+    ("day", 86_400.0),
+    ("week", 604_800.0),
+    ("month", 2_629_746.0),
+    ("year", 31_556_952.0),
+];
+
+/// Default thresholds: below `now` seconds the phrase is "just now", and each
+/// unit is used while its rounded count stays below its threshold.
+const DEFAULT_THRESHOLDS: [(&str, i64); 7] = [
+    ("now", 10),
+    ("second", 60),
+    ("minute", 60),
+    ("hour", 22),
+    ("day", 7),
+    ("week", 5),
+    ("month", 12),
+];
+
+/// Default English phrases. `{n}` is a count, `{time}` a time of day written
+/// with `time_format`, and `{text}` the unit phrase inside `past`/`future`.
+const DEFAULT_PHRASES: [(&str, &str); 21] = [
+    ("now", "just now"),
+    ("past", "{text} ago"),
+    ("future", "in {text}"),
+    ("second", "1 second"),
+    ("seconds", "{n} seconds"),
+    ("minute", "1 minute"),
+    ("minutes", "{n} minutes"),
+    ("hour", "1 hour"),
+    ("hours", "{n} hours"),
+    ("day", "1 day"),
+    ("days", "{n} days"),
+    ("week", "1 week"),
+    ("weeks", "{n} weeks"),
+    ("month", "1 month"),
+    ("months", "{n} months"),
+    ("year", "1 year"),
+    ("years", "{n} years"),
+    ("today", "today at {time}"),
+    ("yesterday", "yesterday at {time}"),
+    ("tomorrow", "tomorrow at {time}"),
+    ("time_format", "%H:%M"),
+];
+
+/// Merges caller overrides into a table of defaults, rejecting unknown keys
+/// so that a misspelt key is not silently ignored.
+fn merge<V: Clone>(defaults: &[(&'static str, V)], overrides: Option<HashMap<String, V>>, what: &str) -> PyResult<HashMap<&'static str, V>> {
+    let mut table: HashMap<&'static str, V> = defaults.iter().cloned().collect();
+    for (key, value) in overrides.unwrap_or_default() {
+        let Some(&(known, _)) = defaults.iter().find(|(name, _)| *name == key) else {
+            return Err(ChronoError::InvalidArgument(format!("unknown {} key '{}'", what, key)).into());
+        };
+        table.insert(known, value);
+    }
+    Ok(table)
+}
+
+/// Describes `utc` relative to `reference` (default: now) as "just now",
+/// "5 minutes ago", "in 3 weeks" or "yesterday at 14:00". Calendar days are
+/// counted on the wall clock of `zone`. `granularity` is the smallest unit
+/// reported; `thresholds` and `phrases` override the defaults above.
+pub fn relative(
+    utc: &DateTime<Utc>,
+    zone: &AnyZone,
+    reference: Option<DateTime<Utc>>,
+    granularity: &str,
+    thresholds: Option<HashMap<String, i64>>,
+    phrases: Option<HashMap<String, String>>,
+) -> PyResult<String> {
+    let Some(smallest) = RELATIVE_UNITS.iter().position(|(name, _)| *name == granularity) else {
+        return Err(ChronoError::InvalidArgument(
+            "granularity must be 'second', 'minute', 'hour', 'day', 'week', 'month' or 'year'".to_string()
+        ).into());
+    };
+    let thresholds = merge(&DEFAULT_THRESHOLDS, thresholds, "threshold")?;
+    let mut phrases = merge(
+        &DEFAULT_PHRASES.map(|(key, phrase)| (key, phrase.to_string())),
+        phrases,
+        "phrase",
+    )?;
+    let mut phrase = |key: &str| phrases.remove(key).unwrap_or_default();
+
+    let reference = reference.unwrap_or_else(Utc::now);
+    let future = *utc > reference;
+    let seconds = (*utc - reference).abs().as_seconds_f64();
+    let day = RELATIVE_UNITS.iter().position(|(name, _)| *name == "day").unwrap_or(3);
+
+    if smallest < day && seconds < thresholds["now"] as f64 {
+        return Ok(phrase("now"));
+    }
+    for (index, &(unit, length)) in RELATIVE_UNITS.iter().enumerate().skip(smallest) {
+        let last = index == RELATIVE_UNITS.len() - 1;
+        let count = if index == day {
+            // Whole calendar days between the two local dates, so that
+            // late last night reads as "yesterday" rather than "1 day ago".
+            let days = zone.naive_local(utc).date().signed_duration_since(zone.naive_local(&reference).date()).num_days();
+            if days.abs() <= 1 {
+                let key = match days { 0 => "today", -1 => "yesterday", _ => "tomorrow" };
+                let local = zone.naive_local(utc);
+                let time = format::strftime(&phrase("time_format"), |f| format::render(local.format(f)))?;
+                return Ok(phrase(key).replace("{time}", &time));
+            }
+            days.abs()
+        } else {
+            (seconds / length).round() as i64
+        };
+        if !last && count >= thresholds.get(unit).copied().unwrap_or(i64::MAX) {
+            continue;
+        }
+        if count == 0 {
+            return Ok(phrase("now"));
+        }
+        let text = if count == 1 { phrase(unit) } else { phrase(&format!("{}s", unit)) };
+        let text = text.replace("{n}", &count.to_string());
+        return Ok(phrase(if future { "future" } else { "past" }).replace("{text}", &text));
+    }
+    Ok(phrase("now"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failure(input: &str) -> (PyParseErrorKind, usize) {
+        match parse_human(input) {
+            Err(ChronoError::ParseFailure(failure)) => (failure.kind, failure.position),
+            other => panic!("{:?} did not fail to parse: {:?}", input, other),
+        }
+    }
+
+    #[test]
+    fn parses_components() {
+        assert_eq!(parse_human("1h30m").unwrap(), Duration::minutes(90));
+        assert_eq!(parse_human("90 minutes").unwrap(), Duration::minutes(90));
+        assert_eq!(parse_human("2w 3d").unwrap(), Duration::days(17));
+        assert_eq!(parse_human("1 hour, 30 minutes and 15 seconds").unwrap(), Duration::seconds(5_415));
+        assert_eq!(parse_human("500ms 250us").unwrap(), Duration::microseconds(500_250));
+        assert_eq!(parse_human("3 Days").unwrap(), Duration::days(3));
+    }
+
+    #[test]
+    fn parses_fractions_and_signs() {
+        assert_eq!(parse_human("1.5h").unwrap(), Duration::minutes(90));
+        assert_eq!(parse_human(".5d").unwrap(), Duration::hours(12));
+        assert_eq!(parse_human("-2h 30m").unwrap(), Duration::minutes(-150));
+        assert_eq!(parse_human("+ 1s").unwrap(), Duration::seconds(1));
+        assert_eq!(parse_human("0.000000001s").unwrap(), Duration::nanoseconds(1));
+    }
+
+    #[test]
+    fn error_positions() {
+        assert_eq!(failure(""), (PyParseErrorKind::TooShort, 0));
+        assert_eq!(failure("5"), (PyParseErrorKind::TooShort, 1));
+        assert_eq!(failure("5 parsecs"), (PyParseErrorKind::Invalid, 2));
+        assert_eq!(failure("1h 3 months"), (PyParseErrorKind::Invalid, 5));
+        assert_eq!(failure("1h and"), (PyParseErrorKind::Invalid, 3));
+        assert_eq!(failure("h"), (PyParseErrorKind::Invalid, 0));
+        assert_eq!(failure("1h 1000000000000000000000000000000w"), (PyParseErrorKind::OutOfRange, 3));
+        assert_eq!(failure("1h 99999999999999999999w"), (PyParseErrorKind::OutOfRange, 0));
+    }
+
+    #[test]
+    fn humanizes() {
+        let duration = Duration::days(3) + Duration::hours(4) + Duration::minutes(35);
+        assert_eq!(humanize(&duration, 2, "long").unwrap(), "3 days, 5 hours");
+        assert_eq!(humanize(&duration, 3, "short").unwrap(), "3d4h35m");
+        assert_eq!(humanize(&-Duration::seconds(1), 2, "long").unwrap(), "-1 second");
+        assert_eq!(humanize(&Duration::zero(), 2, "short").unwrap(), "0s");
+        assert_eq!(humanize(&Duration::milliseconds(1_500), usize::MAX, "short").unwrap(), "1s500ms");
+        assert!(matches!(humanize(&duration, 0, "long"), Err(ChronoError::InvalidArgument(_))));
+        assert!(matches!(humanize(&duration, 2, "medium"), Err(ChronoError::InvalidArgument(_))));
+    }
+}
@@ -0,0 +1,491 @@
+//! ISO 8601 durations such as `P3DT4H5M6.5S`, with a leading `-` for
+//! negative values, and dates and times in every common ISO 8601 form:
+//! basic and extended format, calendar, week and ordinal dates, reduced
+//! precision, and decimal fractions on the last time component.
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+use crate::duration::{self, NANOS_PER_SEC};
+use crate::errors::{ChronoError, PyParseErrorKind};
+
+/// The designators allowed before and after `T`, with their length in
+/// seconds. `Y` and `M` before `T` are recognised only to reject them.
+const DATE_UNITS: [(u8, i128); 4] = [(b'Y', 0), (b'M', 0), (b'W', 7 * 86_400), (b'D', 86_400)];
+const TIME_UNITS: [(u8, i128); 3] = [(b'H', 3_600), (b'M', 60), (b'S', 1)];
+
+/// Parses an ISO 8601 duration. Weeks may be combined with other units, any
+/// component may be fractional (`PT0.5H`), and a leading `-` or `+` gives
+/// the sign. Years and months are rejected since they have no fixed length.
+pub fn parse_duration(input: &str) -> Result<Duration, ChronoError> {
+    let fail = |kind, message: &str, position| ChronoError::parse_at(kind, message, input, position);
+    let bytes = input.as_bytes();
+    let (negative, mut pos) = match bytes.first() {
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+    if bytes.get(pos) != Some(&b'P') {
+        return Err(fail(PyParseErrorKind::Invalid, "expected 'P'", pos));
+    }
+    pos += 1;
+
+    let mut total: i128 = 0;
+    let mut components = 0;
+    let mut units: &[(u8, i128)] = &DATE_UNITS;
+    let mut in_time = false;
+    while pos < bytes.len() {
+        if bytes[pos] == b'T' && !in_time {
+            in_time = true;
+            units = &TIME_UNITS;
+            pos += 1;
+            if pos == bytes.len() {
+                return Err(fail(PyParseErrorKind::TooShort, "expected a time component after 'T'", pos));
+            }
+            continue;
+        }
+
+        let start = pos;
+        let whole_end = start + bytes[start..].iter().take_while(|b| b.is_ascii_digit()).count();
+        if whole_end == start {
+            return Err(fail(PyParseErrorKind::Invalid, "expected a number", start));
+        }
+        let mut end = whole_end;
+        let mut fraction = "";
+        if matches!(bytes.get(end), Some(b'.' | b',')) {
+            let digits = bytes[end + 1..].iter().take_while(|b| b.is_ascii_digit()).count();
+            if digits == 0 {
+                return Err(fail(PyParseErrorKind::Invalid, "expected digits after the decimal mark", end + 1));
+            }
+            fraction = &input[end + 1..end + 1 + digits];
+            end += 1 + digits;
+        }
+
+        let Some(designator) = bytes.get(end) else {
+            return Err(fail(PyParseErrorKind::TooShort, "expected a unit designator", end));
+        };
+        let Some(index) = units.iter().position(|(unit, _)| unit == designator) else {
+            return Err(fail(PyParseErrorKind::Invalid, "unexpected or out-of-order unit designator", end));
+        };
+        let seconds = units[index].1;
+        if seconds == 0 {
+            return Err(fail(
+                PyParseErrorKind::Invalid,
+                duration::NO_FIXED_LENGTH,
+                end,
+            ));
+        }
+        units = &units[index + 1..];
+
+        let out_of_range = || fail(PyParseErrorKind::OutOfRange, "duration is out of range", start);
+        let whole: i128 = input[start..whole_end].parse().map_err(|_| out_of_range())?;
+        let mut nanos = whole
+            .checked_mul(seconds * NANOS_PER_SEC)
+            .ok_or_else(out_of_range)?;
+        nanos += duration::fraction_nanos(fraction, seconds * NANOS_PER_SEC);
+        total = total.checked_add(nanos).ok_or_else(out_of_range)?;
+        components += 1;
+        pos = end + 1;
+
+        // Only the last component may carry a fraction.
+        if !fraction.is_empty() && pos < bytes.len() {
+            return Err(fail(PyParseErrorKind::TooLong, "only the last component may be fractional", pos));
+        }
+    }
+    if components == 0 {
+        return Err(fail(PyParseErrorKind::TooShort, "expected at least one component", pos));
+    }
+
+    let total = if negative { -total } else { total };
+    duration::from_nanos(total).ok_or_else(|| fail(PyParseErrorKind::OutOfRange, "duration is out of range", 0))
+}
+
+/// Formats a duration as `[-]P[nD][T[nH][nM][n[.f]S]]`, with days of exactly
+/// 24 hours and trailing zeros dropped from the fraction. Zero is `PT0S`.
+pub fn format_duration(duration: &Duration) -> String {
+    let sign = if *duration < Duration::zero() { "-" } else { "" };
+    let abs = duration.abs();
+    let secs = abs.num_seconds().unsigned_abs();
+    let nanos = abs.subsec_nanos().unsigned_abs();
+    let (days, hours, minutes, seconds) = (secs / 86_400, secs % 86_400 / 3_600, secs % 3_600 / 60, secs % 60);
+
+    let mut out = format!("{}P", sign);
+    if days > 0 {
+        out += &format!("{}D", days);
+    }
+    if hours == 0 && minutes == 0 && seconds == 0 && nanos == 0 {
+        if days == 0 {
+            out += "T0S";
+        }
+        return out;
+    }
+    out.push('T');
+    if hours > 0 {
+        out += &format!("{}H", hours);
+    }
+    if minutes > 0 {
+        out += &format!("{}M", minutes);
+    }
+    if seconds > 0 || nanos > 0 {
+        out += &seconds.to_string();
+        if nanos > 0 {
+            out += format!(".{:09}", nanos).trim_end_matches('0');
+        }
+        out.push('S');
+    }
+    out
+}
+
+/// A parsed ISO 8601 date or date and time; which one depends on the input.
+pub enum DateOrTime {
+    Date(NaiveDate),
+    Naive(NaiveDateTime),
+    Utc(DateTime<Utc>),
+    Fixed(DateTime<FixedOffset>),
+}
+
+/// A position in the input of `parse_datetime`.
+struct Cursor<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn fail(&self, kind: PyParseErrorKind, message: &str) -> ChronoError {
+        ChronoError::parse_at(kind, message, self.input, self.pos)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    /// The number of digits starting at the cursor.
+    fn digits_ahead(&self) -> usize {
+        self.input.as_bytes()[self.pos..].iter().take_while(|b| b.is_ascii_digit()).count()
+    }
+
+    /// Exactly `count` digits.
+    fn number(&mut self, count: usize, what: &str) -> Result<u32, ChronoError> {
+        if self.digits_ahead() < count {
+            let kind = if self.pos + count > self.input.len() { PyParseErrorKind::TooShort } else { PyParseErrorKind::Invalid };
+            return Err(self.fail(kind, &format!("expected {} digits for the {}", count, what)));
+        }
+        let value = self.input[self.pos..self.pos + count].parse().unwrap_or_default();
+        self.pos += count;
+        Ok(value)
+    }
+
+    /// A decimal fraction after `.` or `,`, if there is one, as nanoseconds
+    /// of a unit `unit_nanos` long.
+    fn fraction(&mut self, unit_nanos: i128) -> Result<Option<i128>, ChronoError> {
+        if !matches!(self.peek(), Some(b'.' | b',')) {
+            return Ok(None);
+        }
+        self.pos += 1;
+        let digits = self.digits_ahead();
+        if digits == 0 {
+            return Err(self.fail(PyParseErrorKind::Invalid, "expected digits after the decimal mark"));
+        }
+        let nanos = duration::fraction_nanos(&self.input[self.pos..self.pos + digits], unit_nanos);
+        self.pos += digits;
+        Ok(Some(nanos))
+    }
+}
+
+/// Parses a calendar (`2024-05-01`, `20240501`), week (`2024-W18-3`,
+/// `2024W183`) or ordinal (`2024-122`, `2024122`) date, or a reduced one
+/// (`2024-05`, `2024`, `2024-W18`), which means its first day. Returns the
+/// date and whether it was complete.
+fn parse_date(cursor: &mut Cursor) -> Result<(NaiveDate, bool), ChronoError> {
+    let year = cursor.number(4, "year")? as i32;
+    let extended = cursor.eat(b'-');
+    let start = cursor.pos;
+    let (date, complete) = if cursor.eat(b'W') {
+        let week = cursor.number(2, "week")?;
+        let day = if (extended && cursor.eat(b'-')) || (!extended && cursor.digits_ahead() > 0) {
+            Some(cursor.number(1, "day of the week")?)
+        } else {
+            None
+        };
+        let weekday = match day.unwrap_or(1) {
+            1 => Weekday::Mon,
+            2 => Weekday::Tue,
+            3 => Weekday::Wed,
+            4 => Weekday::Thu,
+            5 => Weekday::Fri,
+            6 => Weekday::Sat,
+            7 => Weekday::Sun,
+            _ => return Err(ChronoError::parse_at(PyParseErrorKind::OutOfRange, "day of the week must be 1-7", cursor.input, cursor.pos - 1)),
+        };
+        (NaiveDate::from_isoywd_opt(year, week, weekday), day.is_some())
+    } else {
+        match (extended, cursor.digits_ahead()) {
+            (_, 3) => (NaiveDate::from_yo_opt(year, cursor.number(3, "day of the year")?), true),
+            (true, 2) => {
+                let month = cursor.number(2, "month")?;
+                let day = if cursor.eat(b'-') { Some(cursor.number(2, "day")?) } else { None };
+                (NaiveDate::from_ymd_opt(year, month, day.unwrap_or(1)), day.is_some())
+            }
+            (false, 4) => {
+                let month = cursor.number(2, "month")?;
+                (NaiveDate::from_ymd_opt(year, month, cursor.number(2, "day")?), true)
+            }
+            (false, 0) => (NaiveDate::from_yo_opt(year, 1), false),
+            _ => return Err(cursor.fail(PyParseErrorKind::Invalid, "expected a month, an ordinal day or a week")),
+        }
+    };
+    let date = date.ok_or_else(|| ChronoError::parse_at(PyParseErrorKind::OutOfRange, "no such date", cursor.input, start))?;
+    Ok((date, complete))
+}
+
+/// Parses `hh[:mm[:ss]]` or `hhmm[ss]`, where the last component may carry
+/// a decimal fraction. `24:00` is the end of the day: it returns midnight and
+/// `true`, and the caller moves to the next day.
+fn parse_time(cursor: &mut Cursor) -> Result<(NaiveTime, bool), ChronoError> {
+    let start = cursor.pos;
+    let hour = cursor.number(2, "hour")?;
+    let extended = cursor.eat(b':');
+    let mut components = vec![(hour, 3_600 * NANOS_PER_SEC)];
+    for (what, unit) in [("minute", 60 * NANOS_PER_SEC), ("second", NANOS_PER_SEC)] {
+        let present = if extended { components.len() == 1 || cursor.eat(b':') } else { cursor.digits_ahead() >= 2 };
+        if !present {
+            break;
+        }
+        components.push((cursor.number(2, what)?, unit));
+    }
+    let last_unit = components[components.len() - 1].1;
+    let fraction = cursor.fraction(last_unit)?.unwrap_or(0);
+
+    let out_of_range = |message| ChronoError::parse_at(PyParseErrorKind::OutOfRange, message, cursor.input, start);
+    let (minute, second) = (components.get(1).map_or(0, |c| c.0), components.get(2).map_or(0, |c| c.0));
+    if hour == 24 {
+        if minute != 0 || second != 0 || fraction != 0 {
+            return Err(out_of_range("24:00 is the only time allowed in hour 24"));
+        }
+        return Ok((NaiveTime::MIN, true));
+    }
+    if hour > 23 || minute > 59 || second > 60 {
+        return Err(out_of_range("no such time"));
+    }
+    let time = if second == 60 {
+        // A leap second, which chrono represents as a nanosecond overflow of :59.
+        NaiveTime::from_hms_nano_opt(hour, minute, 59, 1_000_000_000 + fraction as u32)
+    } else {
+        let nanos = components.iter().map(|&(value, unit)| i128::from(value) * unit).sum::<i128>() + fraction;
+        NaiveTime::from_num_seconds_from_midnight_opt((nanos / NANOS_PER_SEC) as u32, (nanos % NANOS_PER_SEC) as u32)
+    };
+    Ok((time.ok_or_else(|| out_of_range("no such time"))?, false))
+}
+
+/// The offset after a time: `Z` or a number of hours and minutes.
+enum Offset {
+    Zulu,
+    Fixed(FixedOffset),
+}
+
+/// Parses `Z`, `±hh`, `±hh:mm` or `±hhmm`, if present.
+fn parse_offset(cursor: &mut Cursor) -> Result<Option<Offset>, ChronoError> {
+    if cursor.eat(b'Z') || cursor.eat(b'z') {
+        return Ok(Some(Offset::Zulu));
+    }
+    let sign = match cursor.peek() {
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ => return Ok(None),
+    };
+    let start = cursor.pos;
+    cursor.pos += 1;
+    let hours = cursor.number(2, "offset hours")? as i32;
+    let minutes = if cursor.eat(b':') || cursor.digits_ahead() > 0 { cursor.number(2, "offset minutes")? as i32 } else { 0 };
+    if minutes > 59 {
+        return Err(ChronoError::parse_at(PyParseErrorKind::OutOfRange, "offset minutes must be 0-59", cursor.input, start));
+    }
+    FixedOffset::east_opt(sign * (hours * 3_600 + minutes * 60))
+        .map(|offset| Some(Offset::Fixed(offset)))
+        .ok_or_else(|| ChronoError::parse_at(PyParseErrorKind::OutOfRange, "offset is out of range", cursor.input, start))
+}
+
+/// Parses an ISO 8601 date, or a date and time with an optional offset:
+/// a date alone is a `Date`, a date and time without an offset is `Naive`,
+/// `Z` gives `Utc` and a numeric offset gives `Fixed`. The date and time may
+/// be separated by `T` or a space, and `24:00` means midnight at the end of
+/// the day.
+pub fn parse_datetime(input: &str) -> Result<DateOrTime, ChronoError> {
+    let mut cursor = Cursor { input, pos: 0 };
+    let (date, complete) = parse_date(&mut cursor)?;
+    if cursor.peek().is_none() {
+        return Ok(DateOrTime::Date(date));
+    }
+    if !matches!(cursor.peek(), Some(b'T' | b't' | b' ')) {
+        return Err(cursor.fail(PyParseErrorKind::TooLong, "expected 'T' or the end of the input"));
+    }
+    if !complete {
+        return Err(cursor.fail(PyParseErrorKind::NotEnough, "a time needs a complete date"));
+    }
+    cursor.pos += 1;
+    let (time, end_of_day) = parse_time(&mut cursor)?;
+    let offset = parse_offset(&mut cursor)?;
+    if cursor.peek().is_some() {
+        return Err(cursor.fail(PyParseErrorKind::TooLong, "unexpected trailing input"));
+    }
+
+    let date = if end_of_day { date.succ_opt() } else { Some(date) };
+    let local = date
+        .map(|date| date.and_time(time))
+        .ok_or_else(|| ChronoError::parse_at(PyParseErrorKind::OutOfRange, "no such date", input, 0))?;
+    Ok(match offset {
+        None => DateOrTime::Naive(local),
+        Some(Offset::Zulu) => DateOrTime::Utc(local.and_utc()),
+        Some(Offset::Fixed(offset)) => DateOrTime::Fixed(
+            local
+                .and_local_timezone(offset)
+                .single()
+                .ok_or_else(|| ChronoError::parse_at(PyParseErrorKind::OutOfRange, "no such date", input, 0))?,
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn parse_date_only(input: &str) -> Result<(NaiveDate, bool), ChronoError> {
+        parse_date(&mut Cursor { input, pos: 0 })
+    }
+
+    fn parse_time_only(input: &str) -> Result<(NaiveTime, bool), ChronoError> {
+        parse_time(&mut Cursor { input, pos: 0 })
+    }
+
+    fn naive(input: &str) -> NaiveDateTime {
+        match parse_datetime(input) {
+            Ok(DateOrTime::Naive(dt)) => dt,
+            _ => panic!("{} did not parse as a naive datetime", input),
+        }
+    }
+
+    /// The kind and byte offset of a parse failure.
+    fn position(err: ChronoError) -> (PyParseErrorKind, usize) {
+        match err {
+            ChronoError::ParseFailure(failure) => (failure.kind, failure.position),
+            other => panic!("not a parse failure: {:?}", other),
+        }
+    }
+
+    fn failure(input: &str) -> (PyParseErrorKind, usize) {
+        position(parse_datetime(input).err().unwrap())
+    }
+
+    #[test]
+    fn calendar_dates() {
+        assert_eq!(parse_date_only("2024-05-01").unwrap(), (date(2024, 5, 1), true));
+        assert_eq!(parse_date_only("20240501").unwrap(), (date(2024, 5, 1), true));
+        assert_eq!(parse_date_only("2024-05").unwrap(), (date(2024, 5, 1), false));
+        assert_eq!(parse_date_only("2024").unwrap(), (date(2024, 1, 1), false));
+    }
+
+    #[test]
+    fn week_dates() {
+        assert_eq!(parse_date_only("2024-W18-3").unwrap(), (date(2024, 5, 1), true));
+        assert_eq!(parse_date_only("2024W183").unwrap(), (date(2024, 5, 1), true));
+        assert_eq!(parse_date_only("2024-W18").unwrap(), (date(2024, 4, 29), false));
+        assert_eq!(parse_date_only("2020-W53-7").unwrap(), (date(2021, 1, 3), true));
+    }
+
+    #[test]
+    fn ordinal_dates() {
+        assert_eq!(parse_date_only("2024-122").unwrap(), (date(2024, 5, 1), true));
+        assert_eq!(parse_date_only("2023121").unwrap(), (date(2023, 5, 1), true));
+        assert_eq!(parse_date_only("2024-366").unwrap(), (date(2024, 12, 31), true));
+    }
+
+    #[test]
+    fn times() {
+        let hms = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+        assert_eq!(parse_time_only("10:30:15").unwrap(), (hms(10, 30, 15), false));
+        assert_eq!(parse_time_only("103015").unwrap(), (hms(10, 30, 15), false));
+        assert_eq!(parse_time_only("10:30").unwrap(), (hms(10, 30, 0), false));
+        assert_eq!(parse_time_only("10").unwrap(), (hms(10, 0, 0), false));
+        assert_eq!(
+            parse_time_only("10:30:15.25").unwrap().0,
+            NaiveTime::from_hms_milli_opt(10, 30, 15, 250).unwrap()
+        );
+    }
+
+    #[test]
+    fn fractional_hours_and_minutes() {
+        let hms = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+        assert_eq!(parse_time_only("10.5").unwrap().0, hms(10, 30, 0));
+        assert_eq!(parse_time_only("10,25").unwrap().0, hms(10, 15, 0));
+        assert_eq!(parse_time_only("10:30.25").unwrap().0, hms(10, 30, 15));
+        assert_eq!(parse_time_only("1030.5").unwrap().0, hms(10, 30, 30));
+    }
+
+    #[test]
+    fn end_of_day() {
+        assert_eq!(parse_time_only("24:00").unwrap(), (NaiveTime::MIN, true));
+        assert_eq!(naive("2024-05-01T24:00"), date(2024, 5, 2).and_hms_opt(0, 0, 0).unwrap());
+        assert_eq!(naive("2024-12-31T24:00:00"), date(2025, 1, 1).and_hms_opt(0, 0, 0).unwrap());
+        assert_eq!(failure("2024-05-01T24:30"), (PyParseErrorKind::OutOfRange, 11));
+        assert_eq!(failure("2024-05-01T24:00:00.5"), (PyParseErrorKind::OutOfRange, 11));
+    }
+
+    #[test]
+    fn leap_second() {
+        let dt = naive("2016-12-31T23:59:60");
+        assert_eq!(dt.time(), NaiveTime::from_hms_nano_opt(23, 59, 59, 1_000_000_000).unwrap());
+        let dt = naive("2016-12-31T23:59:60.5");
+        assert_eq!(dt.time(), NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap());
+        assert_eq!(failure("2016-12-31T23:59:61"), (PyParseErrorKind::OutOfRange, 11));
+    }
+
+    #[test]
+    fn offsets() {
+        let midnight = date(2024, 5, 1).and_hms_opt(0, 0, 0).unwrap();
+        assert!(matches!(parse_datetime("2024-05-01T00:00Z"), Ok(DateOrTime::Utc(dt)) if dt.naive_utc() == midnight));
+        match parse_datetime("20240501T0530+0530") {
+            Ok(DateOrTime::Fixed(dt)) => {
+                assert_eq!(dt.offset().local_minus_utc(), 19_800);
+                assert_eq!(dt.naive_utc(), midnight);
+            }
+            _ => panic!("expected a fixed offset"),
+        }
+        assert!(matches!(parse_datetime("2024-05-01 00:00-03"), Ok(DateOrTime::Fixed(dt)) if dt.offset().local_minus_utc() == -10_800));
+        assert!(matches!(parse_datetime("2024-05-01"), Ok(DateOrTime::Date(d)) if d == date(2024, 5, 1)));
+    }
+
+    #[test]
+    fn error_positions() {
+        assert_eq!(failure("202"), (PyParseErrorKind::TooShort, 0));
+        assert_eq!(failure("2024-13-01"), (PyParseErrorKind::OutOfRange, 5));
+        assert_eq!(failure("2024-02-30"), (PyParseErrorKind::OutOfRange, 5));
+        assert_eq!(failure("2024-W18-8"), (PyParseErrorKind::OutOfRange, 9));
+        assert_eq!(failure("2024-1"), (PyParseErrorKind::Invalid, 5));
+        assert_eq!(failure("2024-05-01X"), (PyParseErrorKind::TooLong, 10));
+        assert_eq!(failure("2024-05T10:00"), (PyParseErrorKind::NotEnough, 7));
+        assert_eq!(failure("2024-05-01T10:6"), (PyParseErrorKind::TooShort, 14));
+        assert_eq!(failure("2024-05-01T10:30."), (PyParseErrorKind::Invalid, 17));
+        assert_eq!(failure("2024-05-01T10:30+05:75"), (PyParseErrorKind::OutOfRange, 16));
+        assert_eq!(failure("2024-05-01T10:30:00 "), (PyParseErrorKind::TooLong, 19));
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("P3DT4H5M6.5S").unwrap(), Duration::milliseconds(((3 * 24 + 4) * 3_600 + 5 * 60 + 6) * 1_000 + 500));
+        assert_eq!(parse_duration("-PT0.5H").unwrap(), Duration::minutes(-30));
+        assert_eq!(parse_duration("P1W2D").unwrap(), Duration::days(9));
+        assert_eq!(format_duration(&Duration::milliseconds(93_784_500)), "P1DT2H3M4.5S");
+        assert_eq!(position(parse_duration("P1M").unwrap_err()), (PyParseErrorKind::Invalid, 2));
+        assert_eq!(position(parse_duration("3D").unwrap_err()), (PyParseErrorKind::Invalid, 0));
+    }
+}
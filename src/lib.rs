@@ -1,26 +1,57 @@
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
 
+// pyo3 0.22's `#[pymethods]` and `#[pyfunction]` wrappers convert every
+// `PyResult` into itself, which clippy flags as `useless_conversion`. The
+// wrappers are generated outside the annotated items, so the lint can only be
+// allowed on the modules that define bindings.
+#[allow(clippy::useless_conversion)]
 pub mod datetime;
+#[allow(clippy::useless_conversion)]
 pub mod naive;
+#[allow(clippy::useless_conversion)]
 pub mod duration;
+#[allow(clippy::useless_conversion)]
 pub mod timezone;
+pub mod posix;
+pub mod tzif;
+#[allow(clippy::useless_conversion)]
 pub mod format;
 pub mod constants;
+#[allow(clippy::useless_conversion)]
 pub mod errors;
+#[allow(clippy::useless_conversion)]
 pub mod utils;
+#[allow(clippy::useless_conversion)]
 pub mod weekday;
+#[allow(clippy::useless_conversion)]
 pub mod month;
+#[allow(clippy::useless_conversion)]
+pub mod local_result;
+pub mod calendar;
+#[allow(clippy::useless_conversion)]
+pub mod period;
+pub mod rounding;
+pub mod iso8601;
+pub mod human;
+pub mod locale;
+#[allow(clippy::useless_conversion)]
+pub mod stdlib;
+#[allow(clippy::useless_conversion)]
+pub mod pickle;
 
-use crate::datetime::{PyDateTime, PyDateTimeUtc, PyDateTimeLocal, PyDateTimeFixed};
+use crate::datetime::{PyDateTime, PyDateTimeUtc, PyDateTimeLocal, PyDateTimeFixed, PyDateTimeTz};
 use crate::naive::{PyNaiveDateTime, PyNaiveDate, PyNaiveTime};
 use crate::duration::PyDuration;
-use crate::timezone::{PyFixedOffset, PyUtc, PyLocal};
-use crate::format::{parse_datetime, parse_date, parse_time, parse_from_str, parse_from_rfc3339, parse_from_rfc2822};
+use crate::period::PyPeriod;
+use crate::timezone::{PyFixedOffset, PyUtc, PyLocal, PyTz, PyPosixTz, PyTransition};
+use crate::format::{PyFormatter, parse_datetime, parse_date, parse_time, parse_from_str, parse_from_rfc3339, parse_iso8601, parse_from_rfc2822, parse_from_rfc3339_fixed, parse_from_rfc2822_fixed};
 use crate::constants::ChronoConstants;
 use crate::utils::{now_utc, now_local, timestamp_millis, timestamp_nanos, timestamp_micros};
 use crate::weekday::PyWeekday;
 use crate::month::PyMonth;
+use crate::local_result::PyLocalResult;
+use crate::stdlib::from_stdlib;
 
 #[pymodule]
 fn rusthonian_chrono(m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -28,15 +59,22 @@ fn rusthonian_chrono(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyDateTimeUtc>()?;
     m.add_class::<PyDateTimeLocal>()?;
     m.add_class::<PyDateTimeFixed>()?;
+    m.add_class::<PyDateTimeTz>()?;
     m.add_class::<PyNaiveDateTime>()?;
     m.add_class::<PyNaiveDate>()?;
     m.add_class::<PyNaiveTime>()?;
     m.add_class::<PyDuration>()?;
+    m.add_class::<PyPeriod>()?;
     m.add_class::<PyFixedOffset>()?;
     m.add_class::<PyUtc>()?;
     m.add_class::<PyLocal>()?;
+    m.add_class::<PyTz>()?;
+    m.add_class::<PyPosixTz>()?;
+    m.add_class::<PyTransition>()?;
     m.add_class::<PyWeekday>()?;
     m.add_class::<PyMonth>()?;
+    m.add_class::<PyLocalResult>()?;
+    m.add_class::<PyFormatter>()?;
 
     m.add_function(wrap_pyfunction!(now_utc, m)?)?;
     m.add_function(wrap_pyfunction!(now_local, m)?)?;
@@ -49,10 +87,17 @@ fn rusthonian_chrono(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(parse_time, m)?)?;
     m.add_function(wrap_pyfunction!(parse_from_str, m)?)?;
     m.add_function(wrap_pyfunction!(parse_from_rfc3339, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_iso8601, m)?)?;
     m.add_function(wrap_pyfunction!(parse_from_rfc2822, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_from_rfc3339_fixed, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_from_rfc2822_fixed, m)?)?;
+
+    m.add_function(wrap_pyfunction!(from_stdlib, m)?)?;
 
     let py = m.py();
     ChronoConstants::register_constants(py, m)?;
+    errors::register(m)?;
+    pickle::register(m)?;
 
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
     m.add("__doc__", "Complete Python bindings for the Rust chrono crate")?;
@@ -0,0 +1,144 @@
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+use chrono::{DateTime, Duration, LocalResult, NaiveDateTime, Utc};
+use crate::errors::ChronoError;
+use crate::timezone::AnyZone;
+use crate::pickle;
+
+/// The outcome of mapping a wall-clock time into a time zone, mirroring
+/// chrono's `LocalResult`: no match inside a DST gap, two matches inside a
+/// DST overlap, and a single match otherwise.
+#[pyclass(name = "LocalResult")]
+pub struct PyLocalResult {
+    pub inner: LocalResult<PyObject>,
+}
+
+pickle::immutable_pymethods! {
+#[pymethods]
+impl PyLocalResult {
+    fn single(&self, py: Python) -> Option<PyObject> {
+        match &self.inner {
+            LocalResult::Single(dt) => Some(dt.clone_ref(py)),
+            _ => None,
+        }
+    }
+
+    fn earliest(&self, py: Python) -> Option<PyObject> {
+        match &self.inner {
+            LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => Some(dt.clone_ref(py)),
+            LocalResult::None => None,
+        }
+    }
+
+    fn latest(&self, py: Python) -> Option<PyObject> {
+        match &self.inner {
+            LocalResult::Single(dt) | LocalResult::Ambiguous(_, dt) => Some(dt.clone_ref(py)),
+            LocalResult::None => None,
+        }
+    }
+
+    fn is_none(&self) -> bool {
+        matches!(self.inner, LocalResult::None)
+    }
+
+    fn is_single(&self) -> bool {
+        matches!(self.inner, LocalResult::Single(_))
+    }
+
+    fn is_ambiguous(&self) -> bool {
+        matches!(self.inner, LocalResult::Ambiguous(..))
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        let items = match &self.inner {
+            LocalResult::None => vec![],
+            LocalResult::Single(dt) => vec![dt.clone_ref(py)],
+            LocalResult::Ambiguous(a, b) => vec![a.clone_ref(py), b.clone_ref(py)],
+        };
+        pickle::reduce(py, "LocalResult", PyTuple::new_bound(py, items))
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(match &self.inner {
+            LocalResult::None => "LocalResult.None".to_string(),
+            LocalResult::Single(dt) => format!("LocalResult.Single({})", dt.bind(py).repr()?),
+            LocalResult::Ambiguous(a, b) => {
+                format!("LocalResult.Ambiguous({}, {})", a.bind(py).repr()?, b.bind(py).repr()?)
+            }
+        })
+    }
+}
+}
+
+pub fn local_result(py: Python, local: &NaiveDateTime, zone: &AnyZone) -> PyLocalResult {
+    PyLocalResult {
+        inner: zone.from_local_datetime(local).map(|utc| zone.wrap(py, utc)),
+    }
+}
+
+/// Resolves a wall-clock time in `zone`, applying the `ambiguous` and
+/// `nonexistent` policies accepted by `NaiveDateTime.and_local_timezone`.
+pub fn and_local_timezone(
+    py: Python,
+    local: &NaiveDateTime,
+    zone: &AnyZone,
+    ambiguous: &str,
+    nonexistent: &str,
+) -> PyResult<PyObject> {
+    Ok(zone.wrap(py, resolve(local, zone, ambiguous, nonexistent)?))
+}
+
+/// The UTC instant of a wall-clock time in `zone` under the `ambiguous` and
+/// `nonexistent` policies.
+pub fn resolve(local: &NaiveDateTime, zone: &AnyZone, ambiguous: &str, nonexistent: &str) -> PyResult<DateTime<Utc>> {
+    if !matches!(ambiguous, "raise" | "earliest" | "latest") {
+        return Err(ChronoError::InvalidArgument(
+            "ambiguous must be 'raise', 'earliest' or 'latest'".to_string()
+        ).into());
+    }
+    if !matches!(nonexistent, "raise" | "shift_forward" | "shift_backward") {
+        return Err(ChronoError::InvalidArgument(
+            "nonexistent must be 'raise', 'shift_forward' or 'shift_backward'".to_string()
+        ).into());
+    }
+
+    match zone.from_local_datetime(local) {
+        LocalResult::Single(utc) => Ok(utc),
+        LocalResult::Ambiguous(earliest, latest) => match ambiguous {
+            "earliest" => Ok(earliest),
+            "latest" => Ok(latest),
+            _ => Err(ChronoError::OutOfRange(format!("{} is ambiguous in this time zone", local)).into()),
+        },
+        LocalResult::None => {
+            let transition = gap_end(local, zone)
+                .ok_or_else(|| ChronoError::OutOfRange(format!("{} does not exist in this time zone", local)))?;
+            match nonexistent {
+                "shift_forward" => Ok(transition),
+                "shift_backward" => Ok(transition - Duration::nanoseconds(1)),
+                _ => Err(ChronoError::OutOfRange(format!("{} does not exist in this time zone", local)).into()),
+            }
+        }
+    }
+}
+
+/// Finds the transition that skipped over `local`: the first UTC second whose
+/// wall-clock time lies past it.
+pub fn gap_end(local: &NaiveDateTime, zone: &AnyZone) -> Option<DateTime<Utc>> {
+    const SEARCH_SECS: i64 = 2 * 86_400;
+    let target = local.and_utc().timestamp();
+    let (mut lo, mut hi) = (target - SEARCH_SECS, target + SEARCH_SECS);
+    let wall = |secs: i64| DateTime::from_timestamp(secs, 0).map(|utc| (utc, zone.naive_local(&utc)));
+
+    if wall(lo)?.1 > *local || wall(hi)?.1 <= *local {
+        return None;
+    }
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if wall(mid)?.1 > *local {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    wall(hi).map(|(utc, _)| utc)
+}
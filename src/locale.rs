@@ -0,0 +1,123 @@
+//! Locale-aware formatting and month and weekday names, using the glibc locale
+//! data behind chrono's `unstable-locales` feature. It is compiled in only with
+//! this crate's `locales` feature; without it, passing a locale raises
+//! `NotImplementedError` and everything else stays in English.
+
+use std::fmt::Display;
+use pyo3::prelude::*;
+use chrono::format::{DelayedFormat, StrftimeItems};
+use chrono::{DateTime, Month, NaiveDate, NaiveTime, TimeZone, Weekday};
+use crate::format;
+
+const WEEKDAYS: [&str; 7] = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+
+/// Formats an aware datetime, in `locale` (such as `"de_DE"`) if one is given.
+pub fn format_aware<Tz: TimeZone>(dt: &DateTime<Tz>, fmt: &str, locale: Option<&str>) -> PyResult<String>
+where
+    Tz::Offset: Display,
+{
+    match locale {
+        None => format::strftime(fmt, |f| format::render(dt.format(f))),
+        Some(name) => enabled::format_aware(dt, fmt, name),
+    }
+}
+
+/// Formats a naive date, time or both, in `locale` if one is given.
+pub fn format_naive(date: Option<NaiveDate>, time: Option<NaiveTime>, fmt: &str, locale: Option<&str>) -> PyResult<String> {
+    match locale {
+        None => format::strftime(fmt, |f| format::render(DelayedFormat::new(date, time, StrftimeItems::new(f)))),
+        Some(name) => enabled::format_naive(date, time, fmt, name),
+    }
+}
+
+/// The full or abbreviated name of a weekday, in English by default.
+pub fn weekday_name(weekday: Weekday, locale: Option<&str>, short: bool) -> PyResult<String> {
+    match locale {
+        None => {
+            let name = WEEKDAYS[weekday.num_days_from_monday() as usize];
+            Ok(if short { name[..3].to_string() } else { name.to_string() })
+        }
+        Some(name) => enabled::weekday_name(weekday, name, short),
+    }
+}
+
+/// The full or abbreviated name of a month, in English by default. Localized
+/// names are the standalone form ("Январь" rather than "января") where the
+/// locale distinguishes it.
+pub fn month_name(month: Month, locale: Option<&str>, short: bool) -> PyResult<String> {
+    match locale {
+        None => Ok(if short { month.name()[..3].to_string() } else { month.name().to_string() }),
+        Some(name) => enabled::month_name(month, name, short),
+    }
+}
+
+#[cfg(feature = "locales")]
+mod enabled {
+    use super::*;
+    use pure_rust_locales::{locale_match, Locale};
+    use crate::errors::ChronoError;
+
+    /// Looks up a locale by its glibc name; `de-DE` is accepted for `de_DE`.
+    fn parse(name: &str) -> PyResult<Locale> {
+        Locale::try_from(name.replace('-', "_").as_str())
+            .map_err(|_| ChronoError::InvalidArgument(format!("Unknown locale '{}'", name)).into())
+    }
+
+    pub fn format_aware<Tz: TimeZone>(dt: &DateTime<Tz>, fmt: &str, name: &str) -> PyResult<String>
+    where
+        Tz::Offset: Display,
+    {
+        let locale = parse(name)?;
+        format::strftime(fmt, |f| format::render(dt.format_localized(f, locale)))
+    }
+
+    pub fn format_naive(date: Option<NaiveDate>, time: Option<NaiveTime>, fmt: &str, name: &str) -> PyResult<String> {
+        let locale = parse(name)?;
+        format::strftime(fmt, |f| {
+            format::render(DelayedFormat::new_with_locale(date, time, StrftimeItems::new_with_locale(f, locale), locale))
+        })
+    }
+
+    pub fn weekday_name(weekday: Weekday, name: &str, short: bool) -> PyResult<String> {
+        let locale = parse(name)?;
+        let names = if short { locale_match!(locale => LC_TIME::ABDAY) } else { locale_match!(locale => LC_TIME::DAY) };
+        Ok(names[weekday.num_days_from_sunday() as usize].to_string())
+    }
+
+    pub fn month_name(month: Month, name: &str, short: bool) -> PyResult<String> {
+        let locale = parse(name)?;
+        let names = if short {
+            locale_match!(locale => LC_TIME::AB_ALT_MON).unwrap_or(locale_match!(locale => LC_TIME::ABMON))
+        } else {
+            locale_match!(locale => LC_TIME::ALT_MON).unwrap_or(locale_match!(locale => LC_TIME::MON))
+        };
+        Ok(names[month.number_from_month() as usize - 1].to_string())
+    }
+}
+
+#[cfg(not(feature = "locales"))]
+mod enabled {
+    use super::*;
+
+    fn disabled() -> PyErr {
+        PyErr::new::<pyo3::exceptions::PyNotImplementedError, _>(
+            "Locale support is not enabled; build rusthonian-chrono with the `locales` feature"
+        )
+    }
+
+    pub fn format_aware<Tz: TimeZone>(_dt: &DateTime<Tz>, _fmt: &str, _name: &str) -> PyResult<String> {
+        Err(disabled())
+    }
+
+    pub fn format_naive(_date: Option<NaiveDate>, _time: Option<NaiveTime>, _fmt: &str, _name: &str) -> PyResult<String> {
+        Err(disabled())
+    }
+
+    pub fn weekday_name(_weekday: Weekday, _name: &str, _short: bool) -> PyResult<String> {
+        Err(disabled())
+    }
+
+    pub fn month_name(_month: Month, _name: &str, _short: bool) -> PyResult<String> {
+        Err(disabled())
+    }
+}
@@ -1,5 +1,8 @@
 use pyo3::prelude::*;
 use chrono::Month;
+use crate::pickle;
+use crate::errors::ChronoError;
+use crate::locale;
 
 #[pyclass(name = "Month")]
 #[derive(Clone, Copy)]
@@ -7,6 +10,7 @@ pub struct PyMonth {
     pub inner: Month,
 }
 
+pickle::immutable_pymethods! {
 #[pymethods]
 impl PyMonth {
     #[new]
@@ -24,7 +28,7 @@ impl PyMonth {
             10 => Month::October,
             11 => Month::November,
             12 => Month::December,
-            _ => return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("Month must be 1-12")),
+            _ => return Err(ChronoError::OutOfRange("Month must be 1-12".to_string()).into()),
         };
         Ok(PyMonth { inner: m })
     }
@@ -101,6 +105,23 @@ impl PyMonth {
         PyMonth { inner: self.inner.pred() }
     }
 
+    /// The full name, in English or in `locale` (such as `"de_DE"`, which
+    /// needs the `locales` feature).
+    #[pyo3(signature = (locale=None))]
+    fn name(&self, locale: Option<&str>) -> PyResult<String> {
+        locale::month_name(self.inner, locale, false)
+    }
+
+    /// The abbreviated name, in English or in `locale`.
+    #[pyo3(signature = (locale=None))]
+    fn short_name(&self, locale: Option<&str>) -> PyResult<String> {
+        locale::month_name(self.inner, locale, true)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "Month", (self.inner.number_from_month(),))
+    }
+
     fn __str__(&self) -> String {
         format!("{:?}", self.inner)
     }
@@ -125,3 +146,4 @@ impl PyMonth {
         self.inner.number_from_month() as u64
     }
 }
+}
@@ -1,8 +1,17 @@
 use pyo3::prelude::*;
-use chrono::{NaiveDateTime, NaiveDate, NaiveTime, Datelike, Timelike};
+use pyo3::types::{PyDate, PyDateTime as StdDateTime, PyTime};
+use chrono::{NaiveDateTime, NaiveDate, NaiveTime, Datelike, SubsecRound, Timelike};
 use crate::duration::PyDuration;
 use crate::weekday::PyWeekday;
 use crate::errors::ChronoError;
+use crate::local_result::{self, PyLocalResult};
+use crate::timezone::AnyZone;
+use crate::stdlib;
+use crate::pickle;
+use crate::calendar;
+use crate::locale;
+use crate::period::PyPeriod;
+use crate::rounding::{self, TruncateBy};
 
 #[pyclass(name = "NaiveDateTime")]
 #[derive(Clone, Copy)]
@@ -10,12 +19,32 @@ pub struct PyNaiveDateTime {
     pub inner: NaiveDateTime,
 }
 
+pickle::immutable_pymethods! {
 #[pymethods]
 impl PyNaiveDateTime {
     #[new]
-    #[pyo3(signature = (year, month, day, hour, minute, second, nano=None))]
-    fn new(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32, nano: Option<u32>) -> PyResult<Self> {
-        let date = NaiveDate::from_ymd_opt(year, month, day)
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (year, month=None, day=None, hour=None, minute=None, second=None, nano=None))]
+    fn new(
+        year: &Bound<'_, PyAny>,
+        month: Option<u32>,
+        day: Option<u32>,
+        hour: Option<u32>,
+        minute: Option<u32>,
+        second: Option<u32>,
+        nano: Option<u32>,
+    ) -> PyResult<Self> {
+        if year.downcast::<StdDateTime>().is_ok() {
+            stdlib::check_no_fields("NaiveDateTime", &[month, day, hour, minute, second, nano])?;
+            return Self::from_pydatetime(year);
+        }
+        let (month, day) = (stdlib::required(month, "month")?, stdlib::required(day, "day")?);
+        let (hour, minute, second) = (
+            stdlib::required(hour, "hour")?,
+            stdlib::required(minute, "minute")?,
+            stdlib::required(second, "second")?,
+        );
+        let date = NaiveDate::from_ymd_opt(year.extract()?, month, day)
             .ok_or_else(|| ChronoError::OutOfRange("Invalid date".to_string()))?;
         let time = NaiveTime::from_hms_nano_opt(hour, minute, second, nano.unwrap_or(0))
             .ok_or_else(|| ChronoError::OutOfRange("Invalid time".to_string()))?;
@@ -49,7 +78,7 @@ impl PyNaiveDateTime {
     fn parse_from_str(s: &str, fmt: &str) -> PyResult<Self> {
         NaiveDateTime::parse_from_str(s, fmt)
             .map(|inner| PyNaiveDateTime { inner })
-            .map_err(|e| ChronoError::from(e).into())
+            .map_err(|e| ChronoError::parse(e, s, fmt).into())
     }
 
     fn date(&self) -> PyNaiveDate {
@@ -73,7 +102,7 @@ impl PyNaiveDateTime {
     }
 
     fn timestamp_nanos_opt(&self) -> Option<i64> {
-        Some(self.inner.and_utc().timestamp_nanos_opt()?)
+        self.inner.and_utc().timestamp_nanos_opt()
     }
 
     fn year(&self) -> i32 {
@@ -128,35 +157,107 @@ impl PyNaiveDateTime {
         self.inner.nanosecond()
     }
 
-    fn format(&self, fmt: &str) -> String {
-        self.inner.format(fmt).to_string()
+    #[pyo3(signature = (fmt, locale=None))]
+    fn format(&self, fmt: &str, locale: Option<&str>) -> PyResult<String> {
+        locale::format_naive(Some(self.inner.date()), Some(self.inner.time()), fmt, locale)
     }
 
-    fn __add__(&self, rhs: &PyDuration) -> Self {
-        PyNaiveDateTime {
-            inner: self.inner + rhs.inner,
-        }
+    fn local_result(&self, py: Python, tz: AnyZone) -> PyLocalResult {
+        local_result::local_result(py, &self.inner, &tz)
+    }
+
+    #[pyo3(signature = (tz, ambiguous="raise", nonexistent="raise"))]
+    fn and_local_timezone(&self, py: Python, tz: AnyZone, ambiguous: &str, nonexistent: &str) -> PyResult<PyObject> {
+        local_result::and_local_timezone(py, &self.inner, &tz, ambiguous, nonexistent)
+    }
+
+    fn __add__(&self, rhs: &PyDuration) -> PyResult<Self> {
+        self.checked_add(rhs).ok_or_else(|| ChronoError::overflow().into())
+    }
+
+    fn checked_add(&self, rhs: &PyDuration) -> Option<Self> {
+        self.inner.checked_add_signed(rhs.inner).map(|inner| PyNaiveDateTime { inner })
+    }
+
+    fn checked_sub(&self, rhs: &PyDuration) -> Option<Self> {
+        self.inner.checked_sub_signed(rhs.inner).map(|inner| PyNaiveDateTime { inner })
+    }
+
+    #[pyo3(signature = (months, end_of_month="clamp"))]
+    fn add_months(&self, months: i64, end_of_month: &str) -> PyResult<Self> {
+        calendar::add_months_naive(self.inner, months, end_of_month).map(|inner| PyNaiveDateTime { inner })
+    }
+
+    #[pyo3(signature = (months, end_of_month="clamp"))]
+    fn sub_months(&self, months: i64, end_of_month: &str) -> PyResult<Self> {
+        calendar::add_months_naive(self.inner, months.checked_neg().ok_or_else(ChronoError::overflow)?, end_of_month).map(|inner| PyNaiveDateTime { inner })
+    }
+
+    #[pyo3(signature = (years, end_of_month="clamp"))]
+    fn add_years(&self, years: i32, end_of_month: &str) -> PyResult<Self> {
+        calendar::add_months_naive(self.inner, calendar::years(years.into())?, end_of_month).map(|inner| PyNaiveDateTime { inner })
+    }
+
+    fn round(&self, duration: &PyDuration) -> PyResult<Self> {
+        rounding::round(self.inner, duration.inner).map(|inner| PyNaiveDateTime { inner })
+    }
+
+    fn ceil(&self, duration: &PyDuration) -> PyResult<Self> {
+        rounding::ceil(self.inner, duration.inner).map(|inner| PyNaiveDateTime { inner })
+    }
+
+    fn trunc(&self, by: TruncateBy) -> PyResult<Self> {
+        rounding::trunc_naive(self.inner, by).map(|inner| PyNaiveDateTime { inner })
+    }
+
+    fn round_subsecs(&self, digits: u16) -> Self {
+        PyNaiveDateTime { inner: self.inner.round_subsecs(digits) }
+    }
+
+    fn trunc_subsecs(&self, digits: u16) -> Self {
+        PyNaiveDateTime { inner: self.inner.trunc_subsecs(digits) }
     }
 
     fn __sub__(&self, rhs: &Bound<'_, PyAny>) -> PyResult<PyObject> {
         Python::with_gil(|py| {
             if let Ok(duration) = rhs.downcast::<PyDuration>() {
-                Ok(PyNaiveDateTime {
-                    inner: self.inner - duration.borrow().inner,
-                }.into_py(py))
+                Ok(self.checked_sub(&duration.borrow())
+                    .ok_or_else(ChronoError::overflow)?
+                    .into_py(py))
             } else if let Ok(other) = rhs.downcast::<PyNaiveDateTime>() {
                 let diff = self.inner.signed_duration_since(other.borrow().inner);
                 Ok(PyDuration {
                     inner: diff,
                 }.into_py(py))
+            } else if let Ok(period) = rhs.downcast::<PyPeriod>() {
+                let period = period.get().checked_neg().ok_or_else(ChronoError::overflow)?;
+                Ok(PyNaiveDateTime { inner: period.apply_naive(self.inner)? }.into_py(py))
             } else {
                 Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
-                    "Can only subtract Duration or NaiveDateTime"
+                    "Can only subtract Duration, Period or NaiveDateTime"
                 ))
             }
         })
     }
 
+    /// Converts to a naive `datetime.datetime`. Nanoseconds are truncated to
+    /// microseconds.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_pydatetime<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, StdDateTime>> {
+        stdlib::naive_datetime_to_py(py, &self.inner, None)
+    }
+
+    /// Accepts a naive `datetime.datetime`; aware values raise `TypeError`.
+    #[staticmethod]
+    fn from_pydatetime(dt: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyNaiveDateTime { inner: dt.extract()? })
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        let (secs, nanos) = pickle::naive_time_state(&self.inner.time());
+        pickle::reduce(py, "NaiveDateTime", (self.inner.date().num_days_from_ce(), secs, nanos))
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
@@ -193,6 +294,7 @@ impl PyNaiveDateTime {
         self.inner.and_utc().timestamp() as u64
     }
 }
+}
 
 #[pyclass(name = "NaiveDate")]
 #[derive(Clone, Copy)]
@@ -200,11 +302,18 @@ pub struct PyNaiveDate {
     pub inner: NaiveDate,
 }
 
+pickle::immutable_pymethods! {
 #[pymethods]
 impl PyNaiveDate {
     #[new]
-    fn new(year: i32, month: u32, day: u32) -> PyResult<Self> {
-        NaiveDate::from_ymd_opt(year, month, day)
+    #[pyo3(signature = (year, month=None, day=None))]
+    fn new(year: &Bound<'_, PyAny>, month: Option<u32>, day: Option<u32>) -> PyResult<Self> {
+        if year.downcast::<PyDate>().is_ok() {
+            stdlib::check_no_fields("NaiveDate", &[month, day])?;
+            return Self::from_pydate(year);
+        }
+        let (month, day) = (stdlib::required(month, "month")?, stdlib::required(day, "day")?);
+        NaiveDate::from_ymd_opt(year.extract()?, month, day)
             .map(|inner| PyNaiveDate { inner })
             .ok_or_else(|| ChronoError::OutOfRange("Invalid date".to_string()).into())
     }
@@ -241,17 +350,21 @@ impl PyNaiveDate {
     }
 
     #[staticmethod]
-    fn from_num_days_from_ce(days: i32) -> Self {
-        PyNaiveDate {
-            inner: NaiveDate::from_num_days_from_ce_opt(days).unwrap(),
-        }
+    fn from_num_days_from_ce(days: i32) -> PyResult<Self> {
+        Self::from_num_days_from_ce_opt(days)
+            .ok_or_else(|| ChronoError::OutOfRange("Invalid number of days from CE".to_string()).into())
+    }
+
+    #[staticmethod]
+    fn from_num_days_from_ce_opt(days: i32) -> Option<Self> {
+        NaiveDate::from_num_days_from_ce_opt(days).map(|inner| PyNaiveDate { inner })
     }
 
     #[staticmethod]
     fn parse_from_str(s: &str, fmt: &str) -> PyResult<Self> {
         NaiveDate::parse_from_str(s, fmt)
             .map(|inner| PyNaiveDate { inner })
-            .map_err(|e| ChronoError::from(e).into())
+            .map_err(|e| ChronoError::parse(e, s, fmt).into())
     }
 
     fn and_time(&self, time: &PyNaiveTime) -> PyNaiveDateTime {
@@ -324,43 +437,92 @@ impl PyNaiveDate {
         self.inner.num_days_from_ce()
     }
 
-    fn succ(&self) -> Self {
-        PyNaiveDate { inner: self.inner.succ_opt().unwrap() }
+    fn succ(&self) -> PyResult<Self> {
+        self.succ_opt()
+            .ok_or_else(|| ChronoError::OutOfRange("No date after the maximum date".to_string()).into())
     }
 
-    fn pred(&self) -> Self {
-        PyNaiveDate { inner: self.inner.pred_opt().unwrap() }
+    fn succ_opt(&self) -> Option<Self> {
+        self.inner.succ_opt().map(|inner| PyNaiveDate { inner })
     }
 
-    fn format(&self, fmt: &str) -> String {
-        self.inner.format(fmt).to_string()
+    fn pred(&self) -> PyResult<Self> {
+        self.pred_opt()
+            .ok_or_else(|| ChronoError::OutOfRange("No date before the minimum date".to_string()).into())
     }
 
-    fn __add__(&self, rhs: &PyDuration) -> Self {
-        PyNaiveDate {
-            inner: self.inner + rhs.inner,
-        }
+    fn pred_opt(&self) -> Option<Self> {
+        self.inner.pred_opt().map(|inner| PyNaiveDate { inner })
+    }
+
+    #[pyo3(signature = (fmt, locale=None))]
+    fn format(&self, fmt: &str, locale: Option<&str>) -> PyResult<String> {
+        locale::format_naive(Some(self.inner), None, fmt, locale)
+    }
+
+    fn __add__(&self, rhs: &PyDuration) -> PyResult<Self> {
+        self.checked_add(rhs).ok_or_else(|| ChronoError::overflow().into())
+    }
+
+    fn checked_add(&self, rhs: &PyDuration) -> Option<Self> {
+        self.inner.checked_add_signed(rhs.inner).map(|inner| PyNaiveDate { inner })
+    }
+
+    fn checked_sub(&self, rhs: &PyDuration) -> Option<Self> {
+        self.inner.checked_sub_signed(rhs.inner).map(|inner| PyNaiveDate { inner })
+    }
+
+    #[pyo3(signature = (months, end_of_month="clamp"))]
+    fn add_months(&self, months: i64, end_of_month: &str) -> PyResult<Self> {
+        calendar::add_months(self.inner, months, end_of_month).map(|inner| PyNaiveDate { inner })
+    }
+
+    #[pyo3(signature = (months, end_of_month="clamp"))]
+    fn sub_months(&self, months: i64, end_of_month: &str) -> PyResult<Self> {
+        calendar::add_months(self.inner, months.checked_neg().ok_or_else(ChronoError::overflow)?, end_of_month).map(|inner| PyNaiveDate { inner })
+    }
+
+    #[pyo3(signature = (years, end_of_month="clamp"))]
+    fn add_years(&self, years: i32, end_of_month: &str) -> PyResult<Self> {
+        calendar::add_months(self.inner, calendar::years(years.into())?, end_of_month).map(|inner| PyNaiveDate { inner })
     }
 
     fn __sub__(&self, rhs: &Bound<'_, PyAny>) -> PyResult<PyObject> {
         Python::with_gil(|py| {
             if let Ok(duration) = rhs.downcast::<PyDuration>() {
-                Ok(PyNaiveDate {
-                    inner: self.inner - duration.borrow().inner,
-                }.into_py(py))
+                Ok(self.checked_sub(&duration.borrow())
+                    .ok_or_else(ChronoError::overflow)?
+                    .into_py(py))
             } else if let Ok(other) = rhs.downcast::<PyNaiveDate>() {
                 let diff = self.inner.signed_duration_since(other.borrow().inner);
                 Ok(PyDuration {
                     inner: diff,
                 }.into_py(py))
+            } else if let Ok(period) = rhs.downcast::<PyPeriod>() {
+                let period = period.get().checked_neg().ok_or_else(ChronoError::overflow)?;
+                Ok(PyNaiveDate { inner: period.apply_date(self.inner)? }.into_py(py))
             } else {
                 Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
-                    "Can only subtract Duration or NaiveDate"
+                    "Can only subtract Duration, Period or NaiveDate"
                 ))
             }
         })
     }
 
+    #[allow(clippy::wrong_self_convention)]
+    fn to_pydate<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDate>> {
+        stdlib::date_to_py(py, &self.inner)
+    }
+
+    #[staticmethod]
+    fn from_pydate(date: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyNaiveDate { inner: date.extract()? })
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "NaiveDate", (self.inner.num_days_from_ce(),))
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
@@ -397,6 +559,7 @@ impl PyNaiveDate {
         self.inner.num_days_from_ce() as u64
     }
 }
+}
 
 #[pyclass(name = "NaiveTime")]
 #[derive(Clone, Copy)]
@@ -404,12 +567,18 @@ pub struct PyNaiveTime {
     pub inner: NaiveTime,
 }
 
+pickle::immutable_pymethods! {
 #[pymethods]
 impl PyNaiveTime {
     #[new]
-    #[pyo3(signature = (hour, minute, second, nano=None))]
-    fn new(hour: u32, minute: u32, second: u32, nano: Option<u32>) -> PyResult<Self> {
-        NaiveTime::from_hms_nano_opt(hour, minute, second, nano.unwrap_or(0))
+    #[pyo3(signature = (hour, minute=None, second=None, nano=None))]
+    fn new(hour: &Bound<'_, PyAny>, minute: Option<u32>, second: Option<u32>, nano: Option<u32>) -> PyResult<Self> {
+        if hour.downcast::<PyTime>().is_ok() {
+            stdlib::check_no_fields("NaiveTime", &[minute, second, nano])?;
+            return Self::from_pytime(hour);
+        }
+        let (minute, second) = (stdlib::required(minute, "minute")?, stdlib::required(second, "second")?);
+        NaiveTime::from_hms_nano_opt(hour.extract()?, minute, second, nano.unwrap_or(0))
             .map(|inner| PyNaiveTime { inner })
             .ok_or_else(|| ChronoError::OutOfRange("Invalid time".to_string()).into())
     }
@@ -453,7 +622,7 @@ impl PyNaiveTime {
     fn parse_from_str(s: &str, fmt: &str) -> PyResult<Self> {
         NaiveTime::parse_from_str(s, fmt)
             .map(|inner| PyNaiveTime { inner })
-            .map_err(|e| ChronoError::from(e).into())
+            .map_err(|e| ChronoError::parse(e, s, fmt).into())
     }
 
     fn hour(&self) -> u32 {
@@ -476,8 +645,9 @@ impl PyNaiveTime {
         self.inner.num_seconds_from_midnight()
     }
 
-    fn format(&self, fmt: &str) -> String {
-        self.inner.format(fmt).to_string()
+    #[pyo3(signature = (fmt, locale=None))]
+    fn format(&self, fmt: &str, locale: Option<&str>) -> PyResult<String> {
+        locale::format_naive(None, Some(self.inner), fmt, locale)
     }
 
     fn __add__(&self, rhs: &PyDuration) -> Self {
@@ -505,6 +675,22 @@ impl PyNaiveTime {
         })
     }
 
+    /// Converts to a naive `datetime.time`. Nanoseconds are truncated to
+    /// microseconds.
+    #[allow(clippy::wrong_self_convention)]
+    fn to_pytime<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTime>> {
+        stdlib::time_to_py(py, &self.inner)
+    }
+
+    #[staticmethod]
+    fn from_pytime(time: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(PyNaiveTime { inner: time.extract()? })
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "NaiveTime", pickle::naive_time_state(&self.inner))
+    }
+
     fn __str__(&self) -> String {
         self.inner.to_string()
     }
@@ -541,3 +727,4 @@ impl PyNaiveTime {
         self.inner.num_seconds_from_midnight() as u64
     }
 }
+}
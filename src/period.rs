@@ -0,0 +1,283 @@
+//! A calendar period in the style of `dateutil.relativedelta`: whole months
+//! and days that follow the calendar, plus an exact span of time.
+
+use pyo3::prelude::*;
+use pyo3::types::PyInt;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use crate::calendar;
+use crate::datetime::{PyDateTime, PyDateTimeFixed, PyDateTimeLocal, PyDateTimeTz, PyDateTimeUtc};
+use crate::duration::{self, PyDuration};
+use crate::errors::ChronoError;
+use crate::local_result;
+use crate::naive::{PyNaiveDate, PyNaiveDateTime};
+use crate::pickle;
+use crate::timezone::AnyZone;
+
+fn overflow() -> PyErr {
+    ChronoError::Overflow("Period out of range".to_string()).into()
+}
+
+/// Years and months (kept as a single count of months), days, and an exact
+/// `time` part. Applying a period shifts the calendar date by the months
+/// (clamping to the end of a shorter month), then by the days, and finally
+/// adds the exact time.
+#[pyclass(name = "Period", frozen)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PyPeriod {
+    pub months: i64,
+    pub days: i64,
+    pub time: Duration,
+}
+
+impl PyPeriod {
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(PyPeriod {
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            time: -self.time,
+        })
+    }
+
+    fn sum(&self, other: &Self) -> PyResult<Self> {
+        Ok(PyPeriod {
+            months: self.months.checked_add(other.months).ok_or_else(overflow)?,
+            days: self.days.checked_add(other.days).ok_or_else(overflow)?,
+            time: self.time.checked_add(&other.time).ok_or_else(overflow)?,
+        })
+    }
+
+    /// The calendar part applied to a wall-clock time.
+    fn shift_local(&self, local: NaiveDateTime) -> PyResult<NaiveDateTime> {
+        let local = calendar::add_months_naive(local, self.months, "clamp")?;
+        Duration::try_days(self.days)
+            .and_then(|days| local.checked_add_signed(days))
+            .ok_or_else(|| ChronoError::overflow().into())
+    }
+
+    pub fn apply_date(&self, date: NaiveDate) -> PyResult<NaiveDate> {
+        if !self.time.is_zero() {
+            return Err(ChronoError::InvalidArgument(
+                "A Period with a time part cannot be applied to a NaiveDate".to_string()
+            ).into());
+        }
+        Ok(self.shift_local(date.and_time(NaiveTime::MIN))?.date())
+    }
+
+    pub fn apply_naive(&self, dt: NaiveDateTime) -> PyResult<NaiveDateTime> {
+        self.shift_local(dt)?
+            .checked_add_signed(self.time)
+            .ok_or_else(|| ChronoError::overflow().into())
+    }
+
+    pub fn apply_aware(&self, utc: &DateTime<Utc>, zone: &AnyZone, ambiguous: &str, nonexistent: &str) -> PyResult<DateTime<Utc>> {
+        let local = self.shift_local(zone.naive_local(utc))?;
+        local_result::resolve(&local, zone, ambiguous, nonexistent)?
+            .checked_add_signed(self.time)
+            .ok_or_else(|| ChronoError::overflow().into())
+    }
+
+    fn apply(&self, py: Python, value: &Bound<'_, PyAny>, ambiguous: &str, nonexistent: &str) -> PyResult<PyObject> {
+        if let Ok(date) = value.downcast::<PyNaiveDate>() {
+            return Ok(PyNaiveDate { inner: self.apply_date(date.borrow().inner)? }.into_py(py));
+        }
+        if let Ok(dt) = value.downcast::<PyNaiveDateTime>() {
+            return Ok(PyNaiveDateTime { inner: self.apply_naive(dt.borrow().inner)? }.into_py(py));
+        }
+        if let Ok(dt) = value.downcast::<PyDateTime>() {
+            let utc = self.apply_aware(&dt.borrow().utc, &AnyZone::Utc, ambiguous, nonexistent)?;
+            return Ok(PyDateTime { utc }.into_py(py));
+        }
+        let (utc, zone) = if let Ok(dt) = value.downcast::<PyDateTimeUtc>() {
+            (dt.borrow().inner, AnyZone::Utc)
+        } else if let Ok(dt) = value.downcast::<PyDateTimeLocal>() {
+            (dt.borrow().inner.to_utc(), AnyZone::Local)
+        } else if let Ok(dt) = value.downcast::<PyDateTimeFixed>() {
+            (dt.borrow().inner.to_utc(), AnyZone::Fixed(*dt.borrow().inner.offset()))
+        } else if let Ok(dt) = value.downcast::<PyDateTimeTz>() {
+            (dt.borrow().inner.to_utc(), AnyZone::Zone(dt.borrow().inner.timezone()))
+        } else {
+            return Ok(py.NotImplemented());
+        };
+        let utc = self.apply_aware(&utc, &zone, ambiguous, nonexistent)?;
+        Ok(zone.wrap(py, utc))
+    }
+}
+
+/// The calendar difference from `start` to `end`: the most whole months that
+/// do not pass `end`, then whole days, then the rest.
+fn between(start: NaiveDateTime, end: NaiveDateTime) -> PyResult<PyPeriod> {
+    let mut months = i64::from(end.year() - start.year()) * 12 + i64::from(end.month()) - i64::from(start.month());
+    let shifted = loop {
+        let shifted = calendar::add_months_naive(start, months, "clamp")?;
+        if start <= end && shifted > end {
+            months -= 1;
+        } else if start > end && shifted < end {
+            months += 1;
+        } else {
+            break shifted;
+        }
+    };
+    let rest = end.signed_duration_since(shifted);
+    let days = rest.num_days();
+    Ok(PyPeriod { months, days, time: rest - Duration::days(days) })
+}
+
+pickle::immutable_pymethods! {
+#[pymethods]
+impl PyPeriod {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (*, years=0, months=0, weeks=0, days=0, hours=0, minutes=0, seconds=0, milliseconds=0, microseconds=0, nanoseconds=0))]
+    fn new(
+        years: i64,
+        months: i64,
+        weeks: i64,
+        days: i64,
+        hours: i64,
+        minutes: i64,
+        seconds: i64,
+        milliseconds: i64,
+        microseconds: i64,
+        nanoseconds: i64,
+    ) -> PyResult<Self> {
+        let time = PyDuration::new(
+            None, None, None,
+            Some(hours), Some(minutes), Some(seconds), Some(milliseconds), Some(microseconds), Some(nanoseconds),
+        )?;
+        Ok(PyPeriod {
+            months: years.checked_mul(12).and_then(|m| m.checked_add(months)).ok_or_else(overflow)?,
+            days: weeks.checked_mul(7).and_then(|d| d.checked_add(days)).ok_or_else(overflow)?,
+            time: time.inner,
+        })
+    }
+
+    /// The calendar difference between two `NaiveDate`s or two
+    /// `NaiveDateTime`s, such that `start + Period.between(start, end) == end`
+    /// whenever no end-of-month clamping is involved.
+    #[staticmethod]
+    fn between(start: &Bound<'_, PyAny>, end: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let (Ok(start), Ok(end)) = (start.downcast::<PyNaiveDate>(), end.downcast::<PyNaiveDate>()) {
+            return between(start.borrow().inner.and_time(NaiveTime::MIN), end.borrow().inner.and_time(NaiveTime::MIN));
+        }
+        if let (Ok(start), Ok(end)) = (start.downcast::<PyNaiveDateTime>(), end.downcast::<PyNaiveDateTime>()) {
+            return between(start.borrow().inner, end.borrow().inner);
+        }
+        Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+            "Expected two NaiveDates or two NaiveDateTimes"
+        ))
+    }
+
+    /// Whole years, with the remaining months in `months()`.
+    fn years(&self) -> i64 {
+        self.months / 12
+    }
+
+    fn months(&self) -> i64 {
+        self.months % 12
+    }
+
+    fn total_months(&self) -> i64 {
+        self.months
+    }
+
+    fn days(&self) -> i64 {
+        self.days
+    }
+
+    fn time(&self) -> PyDuration {
+        PyDuration { inner: self.time }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.months == 0 && self.days == 0 && self.time.is_zero()
+    }
+
+    /// Applies the period to a date or datetime. Aware values shift their
+    /// wall-clock time and resolve it with the `ambiguous` and `nonexistent`
+    /// policies of `NaiveDateTime.and_local_timezone`. `+` and `-` use the
+    /// defaults, as `add_months` does.
+    #[pyo3(signature = (value, ambiguous="raise", nonexistent="raise"))]
+    fn add_to(&self, py: Python, value: &Bound<'_, PyAny>, ambiguous: &str, nonexistent: &str) -> PyResult<PyObject> {
+        let result = self.apply(py, value, ambiguous, nonexistent)?;
+        if result.is(&py.NotImplemented()) {
+            return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "Expected NaiveDate, NaiveDateTime or an aware datetime"
+            ));
+        }
+        Ok(result)
+    }
+
+    fn __radd__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        self.apply(py, other, "raise", "raise")
+    }
+
+    /// Adds another period, or applies this one to a date or datetime as
+    /// `__radd__` does.
+    fn __add__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        match other.downcast::<PyPeriod>() {
+            Ok(other) => Ok(self.sum(other.get())?.into_py(py)),
+            Err(_) => self.apply(py, other, "raise", "raise"),
+        }
+    }
+
+    fn __sub__(&self, other: &Self) -> PyResult<Self> {
+        self.sum(&other.checked_neg().ok_or_else(overflow)?)
+    }
+
+    fn __mul__(&self, rhs: &Bound<'_, PyInt>) -> PyResult<Self> {
+        let rhs: i64 = rhs.extract().map_err(|_| overflow())?;
+        Ok(PyPeriod {
+            months: self.months.checked_mul(rhs).ok_or_else(overflow)?,
+            days: self.days.checked_mul(rhs).ok_or_else(overflow)?,
+            time: duration::total_nanos(&self.time)
+                .checked_mul(rhs.into())
+                .and_then(duration::from_nanos)
+                .ok_or_else(overflow)?,
+        })
+    }
+
+    fn __rmul__(&self, lhs: &Bound<'_, PyInt>) -> PyResult<Self> {
+        self.__mul__(lhs)
+    }
+
+    fn __neg__(&self) -> PyResult<Self> {
+        self.checked_neg().ok_or_else(overflow)
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.is_zero()
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn __hash__(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        let (secs, nanos) = pickle::duration_state(&self.time);
+        pickle::reduce(py, "Period", (self.months, self.days, secs, nanos))
+    }
+
+    fn __repr__(&self) -> String {
+        let mut fields = Vec::new();
+        for (name, value) in [("years", self.years()), ("months", self.months()), ("days", self.days)] {
+            if value != 0 {
+                fields.push(format!("{}={}", name, value));
+            }
+        }
+        if !self.time.is_zero() {
+            fields.push(format!("seconds={}", self.time.num_seconds()));
+            if self.time.subsec_nanos() != 0 {
+                fields.push(format!("nanoseconds={}", self.time.subsec_nanos()));
+            }
+        }
+        format!("Period({})", fields.join(", "))
+    }
+}
+}
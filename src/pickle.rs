@@ -0,0 +1,270 @@
+//! Pickle support. Every class reduces to `_unpickle(tag, version, state)`,
+//! where `state` is a small tuple of ints and strings, so pickles stay
+//! compact and older versions can still be read if the layout changes.
+
+use std::sync::Arc;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::PyTuple;
+use chrono::{DateTime, Duration, FixedOffset, Local, LocalResult, Month, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use crate::datetime::{PyDateTime, PyDateTimeFixed, PyDateTimeLocal, PyDateTimeTz, PyDateTimeUtc};
+use crate::duration::PyDuration;
+use crate::period::PyPeriod;
+use crate::errors::{ChronoError, PyParseErrorKind};
+use crate::format::PyFormatter;
+use crate::local_result::PyLocalResult;
+use crate::month::PyMonth;
+use crate::naive::{PyNaiveDate, PyNaiveDateTime, PyNaiveTime};
+use crate::posix::PosixTz;
+use crate::timezone::{PyFixedOffset, PyLocal, PyPosixTz, PyTransition, PyTz, PyUtc, Zone};
+use crate::tzif::{LocalTimeType, TzifZone};
+use crate::weekday::PyWeekday;
+
+pub const PICKLE_VERSION: u8 = 1;
+
+static UNPICKLE: GILOnceCell<PyObject> = GILOnceCell::new();
+
+type TzifState = (String, Vec<i64>, Vec<usize>, Vec<(i32, bool, String)>, Option<String>);
+
+/// Adds `_unpickle` to the module and points every class's `__module__` at
+/// it too, so pickle can find both by name.
+pub fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    let unpickle = wrap_pyfunction!(unpickle, m)?;
+    m.add_function(unpickle.clone())?;
+    let _ = UNPICKLE.set(py, unpickle.into_any().unbind());
+
+    let name = m.name()?;
+    for (_, value) in m.dict() {
+        if value.is_instance_of::<pyo3::types::PyType>() {
+            value.setattr("__module__", &name)?;
+        }
+    }
+    Ok(())
+}
+
+/// The `__reduce__` result for an object of class `tag` with `state`.
+pub fn reduce(py: Python, tag: &'static str, state: impl IntoPy<PyObject>) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+    let unpickle = UNPICKLE.get(py)
+        .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("module is not initialised"))?;
+    Ok((unpickle.clone_ref(py), (tag, PICKLE_VERSION, state.into_py(py))))
+}
+
+/// Wraps a class's `#[pymethods]` block and adds `__copy__` and
+/// `__deepcopy__`. Every class is immutable, so both return the object itself.
+macro_rules! immutable_pymethods {
+    ($(#[$attr:meta])* impl $class:ty { $($body:tt)* }) => {
+        $(#[$attr])*
+        impl $class {
+            $($body)*
+
+            fn __copy__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+                slf
+            }
+
+            fn __deepcopy__<'py>(slf: PyRef<'py, Self>, _memo: &Bound<'py, PyAny>) -> PyRef<'py, Self> {
+                slf
+            }
+        }
+    };
+}
+
+pub(crate) use immutable_pymethods;
+
+fn invalid(tag: &str) -> PyErr {
+    ChronoError::InvalidArgument(format!("Invalid pickle data for {}", tag)).into()
+}
+
+fn instant(secs: i64, nanos: u32) -> Option<DateTime<chrono::Utc>> {
+    DateTime::from_timestamp(secs, nanos)
+}
+
+pub fn instant_state(dt: &DateTime<chrono::Utc>) -> (i64, u32) {
+    (dt.timestamp(), dt.timestamp_subsec_nanos())
+}
+
+pub fn naive_time_state(time: &NaiveTime) -> (u32, u32) {
+    use chrono::Timelike;
+    (time.num_seconds_from_midnight(), time.nanosecond())
+}
+
+pub fn duration_state(duration: &Duration) -> (i64, i32) {
+    (duration.num_seconds(), duration.subsec_nanos())
+}
+
+pub fn zone_state(py: Python, zone: &Zone) -> PyObject {
+    match zone {
+        Zone::Iana(tz) => ("iana", tz.name()).into_py(py),
+        Zone::Posix(tz) => ("posix", tz.source.as_str()).into_py(py),
+        Zone::Tzif(tz) => {
+            let types: Vec<_> = tz.types.iter()
+                .map(|ty| (ty.offset.local_minus_utc(), ty.is_dst, ty.abbreviation.clone()))
+                .collect();
+            let footer = tz.footer.as_ref().map(|footer| footer.source.clone());
+            ("tzif", (tz.name.clone(), tz.transitions.clone(), tz.transition_types.clone(), types, footer)).into_py(py)
+        }
+    }
+}
+
+fn zone_from_state(state: &Bound<'_, PyAny>) -> Option<Zone> {
+    let (kind, data): (String, Bound<'_, PyAny>) = state.extract().ok()?;
+    match kind.as_str() {
+        "iana" => data.extract::<String>().ok()?.parse().ok().map(Zone::Iana),
+        "posix" => PosixTz::parse(&data.extract::<String>().ok()?).ok().map(|tz| Zone::Posix(Arc::new(tz))),
+        "tzif" => {
+            let (name, transitions, transition_types, types, footer): TzifState = data.extract().ok()?;
+            let types = types.into_iter()
+                .map(|(offset, is_dst, abbreviation)| {
+                    Some(LocalTimeType { offset: FixedOffset::east_opt(offset)?, is_dst, abbreviation })
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let footer = match footer {
+                Some(footer) => Some(PosixTz::parse(&footer).ok()?),
+                None => None,
+            };
+            let consistent = !types.is_empty()
+                && transitions.len() == transition_types.len()
+                && transitions.windows(2).all(|w| w[0] < w[1])
+                && transition_types.iter().all(|&idx| idx < types.len());
+            consistent.then(|| Zone::Tzif(Arc::new(TzifZone { name, transitions, transition_types, types, footer })))
+        }
+        _ => None,
+    }
+}
+
+#[pyfunction]
+#[pyo3(name = "_unpickle")]
+fn unpickle(py: Python, tag: &str, version: u8, state: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+    if version != PICKLE_VERSION {
+        return Err(ChronoError::InvalidArgument(format!(
+            "Unsupported pickle version {} for {}", version, tag
+        )).into());
+    }
+    match tag {
+        "Utc" | "Local" | "FixedOffset" | "Tz" | "PosixTz" => restore_tzinfo(py, tag, state),
+        _ => restore(py, tag, state).ok_or_else(|| invalid(tag)),
+    }
+}
+
+/// Rebuilds a time zone. These classes extend `tzinfo`, which pyo3 gives no
+/// `IntoPy`, so they are allocated with `Py::new`.
+fn restore_tzinfo(py: Python, tag: &str, state: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+    let malformed = || invalid(tag);
+    Ok(match tag {
+        "Utc" => Py::new(py, PyUtc)?.into_any(),
+        "Local" => Py::new(py, PyLocal)?.into_any(),
+        "FixedOffset" => {
+            let (secs,) = state.extract().map_err(|_| malformed())?;
+            let inner = FixedOffset::east_opt(secs).ok_or_else(malformed)?;
+            Py::new(py, PyFixedOffset { inner })?.into_any()
+        }
+        "Tz" => {
+            let inner = zone_from_state(state).ok_or_else(malformed)?;
+            Py::new(py, PyTz { inner })?.into_any()
+        }
+        "PosixTz" => {
+            let (source,): (String,) = state.extract().map_err(|_| malformed())?;
+            let rule = PosixTz::parse(&source).map_err(|_| malformed())?;
+            Py::new(py, PyPosixTz { inner: Arc::new(rule) })?.into_any()
+        }
+        _ => return Err(malformed()),
+    })
+}
+
+/// Rebuilds an object from its state, or `None` if the state is malformed.
+fn restore(py: Python, tag: &str, state: &Bound<'_, PyAny>) -> Option<PyObject> {
+    Some(match tag {
+        "DateTime" => {
+            let (secs, nanos) = state.extract().ok()?;
+            PyDateTime { utc: instant(secs, nanos)? }.into_py(py)
+        }
+        "DateTimeUtc" => {
+            let (secs, nanos) = state.extract().ok()?;
+            PyDateTimeUtc { inner: instant(secs, nanos)? }.into_py(py)
+        }
+        "DateTimeLocal" => {
+            let (secs, nanos) = state.extract().ok()?;
+            PyDateTimeLocal { inner: instant(secs, nanos)?.with_timezone(&Local) }.into_py(py)
+        }
+        "DateTimeFixed" => {
+            let (secs, nanos, offset) = state.extract().ok()?;
+            let offset = FixedOffset::east_opt(offset)?;
+            PyDateTimeFixed { inner: instant(secs, nanos)?.with_timezone(&offset) }.into_py(py)
+        }
+        "DateTimeTz" => {
+            let (secs, nanos, zone): (i64, u32, Bound<'_, PyAny>) = state.extract().ok()?;
+            let zone = zone_from_state(&zone)?;
+            PyDateTimeTz { inner: instant(secs, nanos)?.with_timezone(&zone) }.into_py(py)
+        }
+        "NaiveDateTime" => {
+            let (days, secs, nanos) = state.extract().ok()?;
+            let date = NaiveDate::from_num_days_from_ce_opt(days)?;
+            let time = NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos)?;
+            PyNaiveDateTime { inner: NaiveDateTime::new(date, time) }.into_py(py)
+        }
+        "NaiveDate" => {
+            let (days,) = state.extract().ok()?;
+            PyNaiveDate { inner: NaiveDate::from_num_days_from_ce_opt(days)? }.into_py(py)
+        }
+        "NaiveTime" => {
+            let (secs, nanos) = state.extract().ok()?;
+            PyNaiveTime { inner: NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos)? }.into_py(py)
+        }
+        "Duration" => {
+            let (secs, nanos): (i64, i32) = state.extract().ok()?;
+            let inner = Duration::try_seconds(secs)?.checked_add(&Duration::nanoseconds(nanos.into()))?;
+            PyDuration { inner }.into_py(py)
+        }
+        "Period" => {
+            let (months, days, secs, nanos): (i64, i64, i64, i32) = state.extract().ok()?;
+            let time = Duration::try_seconds(secs)?.checked_add(&Duration::nanoseconds(nanos.into()))?;
+            PyPeriod { months, days, time }.into_py(py)
+        }
+        "Transition" => {
+            let (secs, zone): (i64, Bound<'_, PyAny>) = state.extract().ok()?;
+            let zone = zone_from_state(&zone)?;
+            PyTransition {
+                at: instant(secs, 0)?,
+                before: zone.offset_at(secs - 1)?,
+                after: zone.offset_at(secs)?,
+            }
+            .into_py(py)
+        }
+        "Weekday" => {
+            let (day,): (u8,) = state.extract().ok()?;
+            PyWeekday { inner: Weekday::try_from(day).ok()? }.into_py(py)
+        }
+        "Month" => {
+            let (month,): (u8,) = state.extract().ok()?;
+            PyMonth { inner: Month::try_from(month).ok()? }.into_py(py)
+        }
+        "ParseErrorKind" => {
+            let (kind,): (u8,) = state.extract().ok()?;
+            let kinds = [
+                PyParseErrorKind::OutOfRange,
+                PyParseErrorKind::Impossible,
+                PyParseErrorKind::NotEnough,
+                PyParseErrorKind::Invalid,
+                PyParseErrorKind::TooShort,
+                PyParseErrorKind::TooLong,
+                PyParseErrorKind::BadFormat,
+            ];
+            kinds.get(usize::from(kind))?.into_py(py)
+        }
+        "Formatter" => {
+            let (fmt,): (String,) = state.extract().ok()?;
+            PyFormatter::new(&fmt).ok()?.into_py(py)
+        }
+        "LocalResult" => {
+            let items = state.downcast::<PyTuple>().ok()?;
+            let inner = match items.len() {
+                0 => LocalResult::None,
+                1 => LocalResult::Single(items.get_item(0).ok()?.unbind()),
+                2 => LocalResult::Ambiguous(items.get_item(0).ok()?.unbind(), items.get_item(1).ok()?.unbind()),
+                _ => return None,
+            };
+            PyLocalResult { inner }.into_py(py)
+        }
+        _ => return None,
+    })
+}
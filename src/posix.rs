@@ -0,0 +1,368 @@
+//! POSIX `TZ` rule strings such as `"EST5EDT,M3.2.0,M11.1.0"`, including the
+//! RFC 8536 extensions used in TZif footers (hours up to 167 and negative
+//! transition times).
+
+use chrono::{Datelike, FixedOffset, LocalResult, NaiveDate};
+use crate::errors::ChronoError;
+use crate::tzif::LocalTimeType;
+
+const DEFAULT_RULE_TIME: i32 = 2 * 3600;
+
+/// The day a DST transition falls on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RuleDay {
+    /// `Jn`: day 1-365, never counting February 29.
+    Julian1(u16),
+    /// `n`: zero-based day 0-365, counting February 29 in leap years.
+    Julian0(u16),
+    /// `Mm.w.d`: weekday `d` (0 = Sunday) of week `w` (5 = last) of month `m`.
+    MonthWeekDay { month: u32, week: u32, weekday: u32 },
+}
+
+impl RuleDay {
+    fn date(&self, year: i32) -> Option<NaiveDate> {
+        match *self {
+            RuleDay::Julian1(n) => {
+                let leap = NaiveDate::from_ymd_opt(year, 2, 29).is_some();
+                let ordinal = u32::from(n) + u32::from(leap && n >= 60);
+                NaiveDate::from_yo_opt(year, ordinal)
+            }
+            RuleDay::Julian0(n) => NaiveDate::from_yo_opt(year, 1)?
+                .checked_add_days(chrono::Days::new(u64::from(n))),
+            RuleDay::MonthWeekDay { month, week, weekday } => {
+                let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+                let first_weekday = first.weekday().num_days_from_sunday();
+                let mut day = 1 + (weekday + 7 - first_weekday) % 7 + (week - 1) * 7;
+                while NaiveDate::from_ymd_opt(year, month, day).is_none() {
+                    day -= 7;
+                }
+                NaiveDate::from_ymd_opt(year, month, day)
+            }
+        }
+    }
+}
+
+/// The daylight saving part of a POSIX TZ string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DstRule {
+    pub dst: LocalTimeType,
+    pub start: RuleDay,
+    /// Seconds after local midnight, in standard time.
+    pub start_time: i32,
+    pub end: RuleDay,
+    /// Seconds after local midnight, in daylight saving time.
+    pub end_time: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PosixTz {
+    pub source: String,
+    pub std: LocalTimeType,
+    pub rule: Option<DstRule>,
+}
+
+impl PosixTz {
+    pub fn parse(source: &str) -> Result<Self, ChronoError> {
+        let invalid = |msg: &str| ChronoError::InvalidTimezone(format!("'{}': {}", source, msg));
+
+        let mut parser = Parser { s: source.as_bytes(), pos: 0 };
+        let std_name = parser.name().ok_or_else(|| invalid("expected a standard time abbreviation"))?;
+        let std_offset = parser.offset().ok_or_else(|| invalid("expected a UTC offset"))?;
+        let std = local_type(std_name, std_offset, false).ok_or_else(|| invalid("UTC offset out of range"))?;
+
+        let rule = if parser.at_end() {
+            None
+        } else {
+            let dst_name = parser.name().ok_or_else(|| invalid("expected a daylight saving time abbreviation"))?;
+            let dst_offset = if parser.peek().is_some_and(|c| c != b',') {
+                parser.offset().ok_or_else(|| invalid("invalid daylight saving time offset"))?
+            } else {
+                std_offset - 3600
+            };
+            let dst = local_type(dst_name, dst_offset, true).ok_or_else(|| invalid("UTC offset out of range"))?;
+
+            let (start, start_time, end, end_time) = if parser.at_end() {
+                // POSIX leaves the default rule to the implementation; use the
+                // current US rules like glibc does.
+                let start = RuleDay::MonthWeekDay { month: 3, week: 2, weekday: 0 };
+                let end = RuleDay::MonthWeekDay { month: 11, week: 1, weekday: 0 };
+                (start, DEFAULT_RULE_TIME, end, DEFAULT_RULE_TIME)
+            } else {
+                let (start, start_time) = parser.transition().ok_or_else(|| invalid("invalid DST start rule"))?;
+                let (end, end_time) = parser.transition().ok_or_else(|| invalid("invalid DST end rule"))?;
+                (start, start_time, end, end_time)
+            };
+            Some(DstRule { dst, start, start_time, end, end_time })
+        };
+
+        if !parser.at_end() {
+            return Err(invalid("unexpected trailing characters"));
+        }
+
+        Ok(PosixTz { source: source.to_string(), std, rule })
+    }
+
+    pub fn local_type(&self, is_dst: bool) -> &LocalTimeType {
+        match &self.rule {
+            Some(rule) if is_dst => &rule.dst,
+            _ => &self.std,
+        }
+    }
+
+    /// Seconds by which daylight saving time is ahead of standard time, or 0
+    /// outside DST.
+    pub fn dst_savings(&self, is_dst: bool) -> i32 {
+        self.local_type(is_dst).offset.local_minus_utc() - self.std.offset.local_minus_utc()
+    }
+
+    /// UTC instants of the DST start and end in `year`, in chronological
+    /// order, each paired with whether DST is in effect afterwards.
+    pub fn transitions_in_year(&self, year: i32) -> Option<[(i64, bool); 2]> {
+        let rule = self.rule.as_ref()?;
+        let std_offset = i64::from(self.std.offset.local_minus_utc());
+        let dst_offset = i64::from(rule.dst.offset.local_minus_utc());
+
+        let start = local_midnight(rule.start.date(year)?) + i64::from(rule.start_time) - std_offset;
+        let end = local_midnight(rule.end.date(year)?) + i64::from(rule.end_time) - dst_offset;
+        // On a tie the end sorts first so that a DST period which ends exactly
+        // when the next one starts reads as permanent DST.
+        Some(if start < end { [(start, true), (end, false)] } else { [(end, false), (start, true)] })
+    }
+
+    pub fn is_dst_at_utc(&self, timestamp: i64) -> bool {
+        if self.rule.is_none() {
+            return false;
+        }
+        let year = chrono::DateTime::from_timestamp(timestamp + i64::from(self.std.offset.local_minus_utc()), 0)
+            .map_or(1970, |dt| dt.year());
+        let mut is_dst = false;
+        for y in [year - 1, year, year + 1] {
+            for (at, dst_after) in self.transitions_in_year(y).into_iter().flatten() {
+                if at <= timestamp {
+                    is_dst = dst_after;
+                }
+            }
+        }
+        is_dst
+    }
+
+    /// Whether standard and/or daylight saving time can produce a local
+    /// timestamp, earliest instant first.
+    pub fn types_at_local(&self, local: i64) -> LocalResult<bool> {
+        let Some(rule) = &self.rule else {
+            return LocalResult::Single(false);
+        };
+        let std_utc = local - i64::from(self.std.offset.local_minus_utc());
+        let dst_utc = local - i64::from(rule.dst.offset.local_minus_utc());
+        let std_valid = !self.is_dst_at_utc(std_utc);
+        let dst_valid = self.is_dst_at_utc(dst_utc);
+
+        match (std_valid, dst_valid) {
+            (true, true) if dst_utc < std_utc => LocalResult::Ambiguous(true, false),
+            (true, true) => LocalResult::Ambiguous(false, true),
+            (true, false) => LocalResult::Single(false),
+            (false, true) => LocalResult::Single(true),
+            (false, false) => LocalResult::None,
+        }
+    }
+}
+
+fn local_midnight(date: NaiveDate) -> i64 {
+    date.and_time(chrono::NaiveTime::MIN).and_utc().timestamp()
+}
+
+/// Builds a local time type from a POSIX offset, which counts hours west of
+/// UTC.
+fn local_type(name: &str, posix_offset: i32, is_dst: bool) -> Option<LocalTimeType> {
+    Some(LocalTimeType {
+        offset: FixedOffset::west_opt(posix_offset)?,
+        is_dst,
+        abbreviation: name.to_string(),
+    })
+}
+
+struct Parser<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn at_end(&self) -> bool {
+        self.pos == self.s.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        let matched = self.peek() == Some(c);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn take_while(&mut self, pred: impl Fn(u8) -> bool) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(&pred) {
+            self.pos += 1;
+        }
+        // Only ASCII bytes are ever accepted by the predicates below.
+        std::str::from_utf8(&self.s[start..self.pos]).unwrap_or_default()
+    }
+
+    /// An abbreviation: three or more letters, or `<...>` quoted with
+    /// letters, digits and signs.
+    fn name(&mut self) -> Option<&'a str> {
+        let name = if self.eat(b'<') {
+            let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == b'+' || c == b'-');
+            if !self.eat(b'>') {
+                return None;
+            }
+            name
+        } else {
+            self.take_while(|c| c.is_ascii_alphabetic())
+        };
+        (name.len() >= 3).then_some(name)
+    }
+
+    fn number(&mut self) -> Option<i32> {
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if digits.is_empty() || digits.len() > 3 {
+            return None;
+        }
+        digits.parse().ok()
+    }
+
+    /// `[+-]hh[:mm[:ss]]` as signed seconds, with hours up to `max_hours`.
+    fn hms(&mut self, max_hours: i32) -> Option<i32> {
+        let sign = if self.eat(b'-') { -1 } else { self.eat(b'+'); 1 };
+        let hours = self.number()?;
+        let mut secs = hours * 3600;
+        for unit in [60, 1] {
+            if !self.eat(b':') {
+                break;
+            }
+            let value = self.number()?;
+            if value > 59 {
+                return None;
+            }
+            secs += value * unit;
+        }
+        (hours <= max_hours).then_some(sign * secs)
+    }
+
+    fn offset(&mut self) -> Option<i32> {
+        self.hms(24)
+    }
+
+    fn transition(&mut self) -> Option<(RuleDay, i32)> {
+        if !self.eat(b',') {
+            return None;
+        }
+        let day = if self.eat(b'J') {
+            let n = self.number()?;
+            (1..=365).contains(&n).then_some(RuleDay::Julian1(n as u16))?
+        } else if self.eat(b'M') {
+            let month = self.number()?;
+            self.eat(b'.').then_some(())?;
+            let week = self.number()?;
+            self.eat(b'.').then_some(())?;
+            let weekday = self.number()?;
+            if !(1..=12).contains(&month) || !(1..=5).contains(&week) || !(0..=6).contains(&weekday) {
+                return None;
+            }
+            RuleDay::MonthWeekDay { month: month as u32, week: week as u32, weekday: weekday as u32 }
+        } else {
+            let n = self.number()?;
+            (0..=365).contains(&n).then_some(RuleDay::Julian0(n as u16))?
+        };
+        let time = if self.eat(b'/') { self.hms(167)? } else { DEFAULT_RULE_TIME };
+        Some((day, time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> i64 {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, min, 0).unwrap().and_utc().timestamp()
+    }
+
+    #[test]
+    fn parses_standard_time_only() {
+        let tz = PosixTz::parse("<+0545>-5:45").unwrap();
+        assert_eq!(tz.std.abbreviation, "+0545");
+        assert_eq!(tz.std.offset.local_minus_utc(), 20_700);
+        assert!(tz.rule.is_none());
+        assert!(!tz.is_dst_at_utc(utc(2024, 7, 1, 0, 0)));
+        assert_eq!(tz.types_at_local(utc(2024, 7, 1, 0, 0)), LocalResult::Single(false));
+    }
+
+    #[test]
+    fn parses_rules() {
+        let tz = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        let rule = tz.rule.as_ref().unwrap();
+        assert_eq!(tz.std.offset.local_minus_utc(), -18_000);
+        assert_eq!(rule.dst.offset.local_minus_utc(), -14_400);
+        assert_eq!(rule.start, RuleDay::MonthWeekDay { month: 3, week: 2, weekday: 0 });
+        assert_eq!((rule.start_time, rule.end_time), (7_200, 7_200));
+        assert_eq!(PosixTz::parse("EST5EDT").unwrap().rule.map(|rule| rule.end), Some(rule.end));
+
+        let tz = PosixTz::parse("<-03>3<-02>,M3.5.0/-2,M10.5.0/-1").unwrap();
+        assert_eq!(tz.rule.map(|rule| (rule.start_time, rule.end_time)), Some((-7_200, -3_600)));
+    }
+
+    #[test]
+    fn rejects_malformed_rules() {
+        for source in ["", "EST", "EST5EDT,M3.2.0", "EST5EDT,M13.1.0,M11.1.0", "EST5EDT,J0,J365", "EST25", "EST5x", "<EST5"] {
+            assert!(matches!(PosixTz::parse(source), Err(ChronoError::InvalidTimezone(_))), "{:?}", source);
+        }
+    }
+
+    #[test]
+    fn rule_days() {
+        assert_eq!(RuleDay::Julian1(60).date(2024), NaiveDate::from_ymd_opt(2024, 3, 1));
+        assert_eq!(RuleDay::Julian1(60).date(2023), NaiveDate::from_ymd_opt(2023, 3, 1));
+        assert_eq!(RuleDay::Julian0(59).date(2024), NaiveDate::from_ymd_opt(2024, 2, 29));
+        let last_sunday = RuleDay::MonthWeekDay { month: 10, week: 5, weekday: 0 };
+        assert_eq!(last_sunday.date(2024), NaiveDate::from_ymd_opt(2024, 10, 27));
+    }
+
+    #[test]
+    fn northern_transitions() {
+        let tz = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        assert_eq!(tz.transitions_in_year(2024), Some([(utc(2024, 3, 10, 7, 0), true), (utc(2024, 11, 3, 6, 0), false)]));
+        assert!(!tz.is_dst_at_utc(utc(2024, 3, 10, 6, 59)));
+        assert!(tz.is_dst_at_utc(utc(2024, 3, 10, 7, 0)));
+        assert!(!tz.is_dst_at_utc(utc(2024, 11, 3, 6, 0)));
+        assert_eq!(tz.dst_savings(true), 3_600);
+    }
+
+    #[test]
+    fn southern_transitions() {
+        let tz = PosixTz::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        assert_eq!(tz.transitions_in_year(2024), Some([(utc(2024, 4, 6, 16, 0), false), (utc(2024, 10, 5, 16, 0), true)]));
+        assert!(tz.is_dst_at_utc(utc(2024, 1, 1, 0, 0)));
+        assert!(!tz.is_dst_at_utc(utc(2024, 7, 1, 0, 0)));
+    }
+
+    #[test]
+    fn permanent_dst() {
+        let tz = PosixTz::parse("EST5EDT,0/0,J365/25").unwrap();
+        for timestamp in [utc(2024, 1, 1, 5, 0), utc(2024, 7, 1, 0, 0), utc(2024, 12, 31, 23, 0)] {
+            assert!(tz.is_dst_at_utc(timestamp));
+        }
+    }
+
+    #[test]
+    fn local_times() {
+        let tz = PosixTz::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+        assert_eq!(tz.types_at_local(utc(2024, 1, 15, 12, 0)), LocalResult::Single(false));
+        assert_eq!(tz.types_at_local(utc(2024, 7, 15, 12, 0)), LocalResult::Single(true));
+        assert_eq!(tz.types_at_local(utc(2024, 3, 10, 2, 30)), LocalResult::None);
+        assert_eq!(tz.types_at_local(utc(2024, 11, 3, 1, 30)), LocalResult::Ambiguous(true, false));
+    }
+}
@@ -0,0 +1,123 @@
+//! Rounding and truncation of datetimes, by a `Duration` (counted from the
+//! Unix epoch in local time, as chrono's `DurationRound` does) or to the start
+//! of a calendar unit.
+
+use pyo3::prelude::*;
+use chrono::{DateTime, Datelike, Days, Duration, DurationRound, NaiveDate, NaiveDateTime, NaiveTime, RoundingError, TimeZone, Utc};
+use crate::duration::PyDuration;
+use crate::errors::ChronoError;
+use crate::local_result;
+use crate::timezone::AnyZone;
+
+/// A calendar unit that `trunc` can snap to.
+#[derive(Clone, Copy)]
+pub enum Unit {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Unit {
+    /// The first day of the unit containing `date`; weeks start on Monday.
+    fn start(self, date: NaiveDate) -> Option<NaiveDate> {
+        let first_of = |month| NaiveDate::from_ymd_opt(date.year(), month, 1);
+        match self {
+            Unit::Day => Some(date),
+            Unit::Week => date.checked_sub_days(Days::new(date.weekday().num_days_from_monday().into())),
+            Unit::Month => first_of(date.month()),
+            Unit::Quarter => first_of(date.month0() / 3 * 3 + 1),
+            Unit::Year => first_of(1),
+        }
+    }
+}
+
+/// The argument of `trunc`: a `Duration` or the name of a calendar unit.
+pub enum TruncateBy {
+    Duration(Duration),
+    Unit(Unit),
+}
+
+impl<'py> FromPyObject<'py> for TruncateBy {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(duration) = ob.downcast::<PyDuration>() {
+            return Ok(TruncateBy::Duration(duration.borrow().inner));
+        }
+        let name = ob.extract::<&str>().map_err(|_| {
+            PyErr::new::<pyo3::exceptions::PyTypeError, _>("Expected a Duration or a calendar unit name")
+        })?;
+        let unit = match name {
+            "day" => Unit::Day,
+            "week" => Unit::Week,
+            "month" => Unit::Month,
+            "quarter" => Unit::Quarter,
+            "year" => Unit::Year,
+            _ => {
+                return Err(ChronoError::InvalidArgument(
+                    "unit must be 'day', 'week', 'month', 'quarter' or 'year'".to_string()
+                ).into())
+            }
+        };
+        Ok(TruncateBy::Unit(unit))
+    }
+}
+
+fn rounding_error(e: RoundingError) -> PyErr {
+    ChronoError::OutOfRange(format!("cannot round: {}", e)).into()
+}
+
+/// `by`, if it is a usable rounding step.
+fn positive(by: Duration) -> PyResult<Duration> {
+    if by <= Duration::zero() {
+        return Err(ChronoError::InvalidArgument("duration must be positive".to_string()).into());
+    }
+    Ok(by)
+}
+
+/// Rounds to the nearest multiple of `by`; halfway rounds up.
+pub fn round<T: DurationRound<Err = RoundingError>>(value: T, by: Duration) -> PyResult<T> {
+    value.duration_round(positive(by)?).map_err(rounding_error)
+}
+
+/// Rounds up to a multiple of `by`.
+pub fn ceil<T: DurationRound<Err = RoundingError>>(value: T, by: Duration) -> PyResult<T> {
+    value.duration_round_up(positive(by)?).map_err(rounding_error)
+}
+
+/// Rounds down to a multiple of `by`.
+pub fn trunc<T: DurationRound<Err = RoundingError>>(value: T, by: Duration) -> PyResult<T> {
+    value.duration_trunc(positive(by)?).map_err(rounding_error)
+}
+
+/// `trunc` on a `NaiveDateTime`: to a multiple of a `Duration`, or to
+/// midnight at the start of a calendar unit.
+pub fn trunc_naive(local: NaiveDateTime, by: TruncateBy) -> PyResult<NaiveDateTime> {
+    match by {
+        TruncateBy::Duration(duration) => trunc(local, duration),
+        TruncateBy::Unit(unit) => start_of(local, unit),
+    }
+}
+
+/// `trunc` on an aware datetime `dt` in `zone`: to a multiple of a `Duration`
+/// on its wall clock, or to the start of a calendar unit as `start_of_aware`
+/// finds it. Returns the instant, for the caller to put back in its zone.
+pub fn trunc_aware<Tz: TimeZone>(dt: DateTime<Tz>, zone: &AnyZone, by: TruncateBy) -> PyResult<DateTime<Utc>> {
+    match by {
+        TruncateBy::Duration(duration) => trunc(dt, duration).map(|dt| dt.to_utc()),
+        TruncateBy::Unit(unit) => start_of_aware(&dt.to_utc(), zone, unit),
+    }
+}
+
+/// Midnight at the start of the unit containing `local`.
+pub fn start_of(local: NaiveDateTime, unit: Unit) -> PyResult<NaiveDateTime> {
+    let date = unit.start(local.date()).ok_or_else(ChronoError::overflow)?;
+    Ok(date.and_time(NaiveTime::MIN))
+}
+
+/// The start of the unit containing `utc` on the wall clock of `zone`. A
+/// midnight skipped by a DST change resolves to the first instant after it.
+pub fn start_of_aware(utc: &DateTime<Utc>, zone: &AnyZone, unit: Unit) -> PyResult<DateTime<Utc>> {
+    let local = start_of(zone.naive_local(utc), unit)?;
+    local_result::resolve(&local, zone, "earliest", "shift_forward")
+}
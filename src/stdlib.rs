@@ -0,0 +1,258 @@
+//! Conversions to and from the standard library's `datetime` module.
+//!
+//! pyo3's own chrono conversions panic on values the stdlib cannot
+//! represent (years outside 1-9999, huge timedeltas), so these helpers build
+//! the stdlib objects themselves and surface such cases as Python errors.
+
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError};
+use pyo3::types::{
+    timezone_utc_bound, PyDate, PyDateAccess, PyDateTime as StdDateTime, PyDelta, PyTime,
+    PyTimeAccess, PyTzInfo, PyTzInfoAccess,
+};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Timelike, Utc};
+use crate::datetime::PyDateTimeFixed;
+use crate::duration::PyDuration;
+use crate::errors::ChronoError;
+use crate::local_result;
+use crate::naive::{PyNaiveDate, PyNaiveDateTime, PyNaiveTime};
+use crate::timezone::{AnyZone, Zone};
+
+/// Microseconds of a time, folding a leap second into the last microsecond
+/// of the preceding second since the stdlib has no leap seconds.
+fn microsecond(time: &NaiveTime) -> u32 {
+    (time.nanosecond() / 1_000).min(999_999)
+}
+
+pub fn date_to_py<'py>(py: Python<'py>, date: &NaiveDate) -> PyResult<Bound<'py, PyDate>> {
+    PyDate::new_bound(py, date.year(), date.month() as u8, date.day() as u8)
+}
+
+pub fn time_to_py<'py>(py: Python<'py>, time: &NaiveTime) -> PyResult<Bound<'py, PyTime>> {
+    PyTime::new_bound(py, time.hour() as u8, time.minute() as u8, time.second() as u8, microsecond(time), None)
+}
+
+pub fn naive_datetime_to_py<'py>(
+    py: Python<'py>,
+    dt: &NaiveDateTime,
+    tzinfo: Option<&Bound<'py, PyTzInfo>>,
+) -> PyResult<Bound<'py, StdDateTime>> {
+    StdDateTime::new_bound(
+        py,
+        dt.year(),
+        dt.month() as u8,
+        dt.day() as u8,
+        dt.hour() as u8,
+        dt.minute() as u8,
+        dt.second() as u8,
+        microsecond(&dt.time()),
+        tzinfo,
+    )
+}
+
+/// Converts an aware datetime to a stdlib `datetime` with a fixed-offset
+/// `timezone`, or `timezone.utc` for a zero offset.
+pub fn aware_to_py<'py, Tz: TimeZone>(py: Python<'py>, dt: &DateTime<Tz>) -> PyResult<Bound<'py, StdDateTime>> {
+    let tzinfo = fixed_offset_to_py(py, dt.offset().fix())?;
+    naive_datetime_to_py(py, &dt.naive_local(), Some(&tzinfo))
+}
+
+/// Converts an instant to a stdlib `datetime` showing its wall-clock time in
+/// `zone` with `tzinfo` attached, setting `fold` on the second reading of a
+/// repeated hour.
+pub fn zoned_to_py<'py>(
+    py: Python<'py>,
+    zone: &AnyZone,
+    utc: &DateTime<Utc>,
+    tzinfo: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, StdDateTime>> {
+    let local = zone.naive_local(utc);
+    let fold = matches!(zone.from_local_datetime(&local), LocalResult::Ambiguous(_, latest) if latest == *utc);
+    StdDateTime::new_bound_with_fold(
+        py,
+        local.year(),
+        local.month() as u8,
+        local.day() as u8,
+        local.hour() as u8,
+        local.minute() as u8,
+        local.second() as u8,
+        microsecond(&local.time()),
+        Some(tzinfo.downcast::<PyTzInfo>()?),
+        fold,
+    )
+}
+
+pub fn fixed_offset_to_py(py: Python<'_>, offset: FixedOffset) -> PyResult<Bound<'_, PyTzInfo>> {
+    if offset.local_minus_utc() == 0 {
+        return Ok(timezone_utc_bound(py));
+    }
+    Ok(offset.into_py(py).into_bound(py).downcast_into::<PyTzInfo>()?)
+}
+
+pub fn duration_to_py<'py>(py: Python<'py>, duration: &Duration) -> PyResult<Bound<'py, PyDelta>> {
+    let days = duration.num_days();
+    let rest = *duration - Duration::days(days);
+    let seconds = rest.num_seconds();
+    let micros = (rest - Duration::seconds(seconds)).num_microseconds().unwrap_or(0);
+    let days = i32::try_from(days)
+        .map_err(|_| PyOverflowError::new_err("Duration is too large for datetime.timedelta"))?;
+    PyDelta::new_bound(py, days, seconds as i32, micros as i32, true)
+}
+
+fn fields_from_py(dt: &Bound<'_, StdDateTime>) -> PyResult<NaiveDateTime> {
+    let date = NaiveDate::from_ymd_opt(dt.get_year(), dt.get_month().into(), dt.get_day().into())
+        .ok_or_else(|| ChronoError::OutOfRange("Invalid date".to_string()))?;
+    let time = NaiveTime::from_hms_micro_opt(
+        dt.get_hour().into(),
+        dt.get_minute().into(),
+        dt.get_second().into(),
+        dt.get_microsecond(),
+    )
+    .ok_or_else(|| ChronoError::OutOfRange("Invalid time".to_string()))?;
+    Ok(NaiveDateTime::new(date, time))
+}
+
+/// Reads a tz-aware stdlib `datetime`, keeping the wall-clock time and the
+/// UTC offset its `tzinfo` reports for it.
+pub fn aware_from_py(ob: &Bound<'_, PyAny>) -> PyResult<DateTime<FixedOffset>> {
+    let dt = ob.downcast::<StdDateTime>()?;
+    if dt.get_tzinfo_bound().is_none() {
+        return Err(PyTypeError::new_err("expected a datetime with non-None tzinfo"));
+    }
+    let offset: Duration = dt.call_method0("utcoffset")?.extract()?;
+    let offset = i32::try_from(offset.num_seconds()).ok()
+        .and_then(FixedOffset::east_opt)
+        .ok_or_else(|| ChronoError::OutOfRange("UTC offset out of range".to_string()))?;
+    fields_from_py(dt)?
+        .and_local_timezone(offset)
+        .single()
+        .ok_or_else(|| ChronoError::OutOfRange("Datetime out of range".to_string()).into())
+}
+
+/// Reads a stdlib `datetime` as a UTC instant; naive values are taken to be
+/// in UTC already, like the fields passed to `DateTime(...)`.
+pub fn instant_from_py(ob: &Bound<'_, PyAny>) -> PyResult<DateTime<Utc>> {
+    let dt = ob.downcast::<StdDateTime>()?;
+    if dt.get_tzinfo_bound().is_none() {
+        Ok(fields_from_py(dt)?.and_utc())
+    } else {
+        Ok(aware_from_py(dt)?.to_utc())
+    }
+}
+
+/// Maps a stdlib `tzinfo` onto one of our zones when there is an exact
+/// equivalent: one of our own zone classes, `timezone.utc`, or a
+/// `zoneinfo.ZoneInfo` whose key chrono-tz knows.
+pub fn zone_from_tzinfo(tzinfo: &Bound<'_, PyTzInfo>) -> Option<AnyZone> {
+    if let Ok(zone) = tzinfo.extract() {
+        return Some(zone);
+    }
+    if tzinfo.is(&timezone_utc_bound(tzinfo.py())) {
+        return Some(AnyZone::Utc);
+    }
+    let key: String = tzinfo.getattr("key").ok()?.extract().ok()?;
+    key.parse().ok().map(|tz| AnyZone::Zone(Zone::Iana(tz)))
+}
+
+/// The instant whose offset applies to a stdlib wall-clock time in `zone`,
+/// following PEP 495: `fold=1` picks the later reading in a repeated hour,
+/// and inside a gap `fold=0` keeps the offset from before the transition.
+/// `None` stands for `dt=None`, which only fixed zones can answer.
+fn reference_instant(zone: &AnyZone, dt: &Bound<'_, PyAny>) -> PyResult<Option<DateTime<Utc>>> {
+    if dt.is_none() {
+        return Ok(matches!(zone, AnyZone::Utc | AnyZone::Fixed(_)).then_some(DateTime::UNIX_EPOCH));
+    }
+    let dt = dt.downcast::<StdDateTime>()?;
+    let local = fields_from_py(dt)?;
+    let fold = dt.get_fold();
+    Ok(Some(match zone.from_local_datetime(&local) {
+        LocalResult::Single(utc) => utc,
+        LocalResult::Ambiguous(earliest, latest) => if fold { latest } else { earliest },
+        LocalResult::None => {
+            let transition = local_result::gap_end(&local, zone).unwrap_or_else(|| local.and_utc());
+            if fold { transition } else { transition - Duration::seconds(1) }
+        }
+    }))
+}
+
+fn seconds_to_py(py: Python<'_>, seconds: i32) -> PyResult<Bound<'_, PyDelta>> {
+    PyDelta::new_bound(py, 0, seconds, 0, true)
+}
+
+/// `tzinfo.utcoffset` for our zone classes.
+pub fn tz_utcoffset<'py>(zone: &AnyZone, dt: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+    reference_instant(zone, dt)?
+        .map(|utc| seconds_to_py(dt.py(), zone.offset_at(&utc).local_minus_utc()))
+        .transpose()
+}
+
+/// `tzinfo.dst`, which like `datetime.timezone` is unknown for fixed zones.
+pub fn tz_dst<'py>(zone: &AnyZone, dt: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+    if matches!(zone, AnyZone::Utc | AnyZone::Fixed(_)) {
+        return Ok(None);
+    }
+    reference_instant(zone, dt)?
+        .map(|utc| seconds_to_py(dt.py(), zone.dst_at(&utc)))
+        .transpose()
+}
+
+/// `tzinfo.tzname`: the zone abbreviation, or the offset where there is none.
+pub fn tz_tzname(zone: &AnyZone, dt: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+    Ok(reference_instant(zone, dt)?.map(|utc| zone.abbreviation(&utc)))
+}
+
+/// `tzinfo.fromutc`: reads `dt`'s fields as UTC and returns the same instant
+/// as wall-clock time in `zone`.
+pub fn tz_fromutc<'py>(
+    zone: &AnyZone,
+    tzinfo: &Bound<'py, PyAny>,
+    dt: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, StdDateTime>> {
+    let dt = dt.downcast::<StdDateTime>()?;
+    if !dt.get_tzinfo_bound().is_some_and(|own| own.is(tzinfo)) {
+        return Err(PyValueError::new_err("fromutc: dt.tzinfo is not self"));
+    }
+    zoned_to_py(dt.py(), zone, &fields_from_py(dt)?.and_utc(), tzinfo)
+}
+
+/// Rejects extra constructor arguments once the first one turned out to be a
+/// stdlib object.
+pub fn check_no_fields(class: &str, fields: &[Option<u32>]) -> PyResult<()> {
+    if fields.iter().any(Option::is_some) {
+        return Err(PyTypeError::new_err(format!(
+            "{}() takes either a standard library object or individual fields, not both", class
+        )));
+    }
+    Ok(())
+}
+
+pub fn required(value: Option<u32>, name: &str) -> PyResult<u32> {
+    value.ok_or_else(|| PyTypeError::new_err(format!("missing required argument '{}'", name)))
+}
+
+/// Converts any stdlib `datetime`, `date`, `time` or `timedelta` into the
+/// matching class; aware datetimes become the class matching their `tzinfo`,
+/// falling back to `DateTimeFixed`.
+#[pyfunction]
+pub fn from_stdlib(py: Python, value: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+    if let Ok(dt) = value.downcast::<StdDateTime>() {
+        let Some(tzinfo) = dt.get_tzinfo_bound() else {
+            return Ok(PyNaiveDateTime { inner: value.extract()? }.into_py(py));
+        };
+        let fixed = aware_from_py(dt)?;
+        return Ok(match zone_from_tzinfo(&tzinfo) {
+            Some(zone) => zone.wrap(py, fixed.to_utc()),
+            None => PyDateTimeFixed { inner: fixed }.into_py(py),
+        });
+    }
+    if value.downcast::<PyDate>().is_ok() {
+        return Ok(PyNaiveDate { inner: value.extract()? }.into_py(py));
+    }
+    if value.downcast::<PyTime>().is_ok() {
+        return Ok(PyNaiveTime { inner: value.extract()? }.into_py(py));
+    }
+    if value.downcast::<PyDelta>().is_ok() {
+        return Ok(PyDuration { inner: value.extract()? }.into_py(py));
+    }
+    Err(PyTypeError::new_err("Expected a datetime, date, time or timedelta"))
+}
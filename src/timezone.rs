@@ -1,10 +1,24 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::sync::Arc;
 use pyo3::prelude::*;
-use chrono::FixedOffset;
+use pyo3::types::{PyDateTime as StdDateTime, PyDelta, PyTzInfo};
+use chrono::{DateTime, Datelike, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone, Utc};
+use chrono_tz::{OffsetComponents, Tz, TZ_VARIANTS};
+use crate::datetime::{PyDateTime, PyDateTimeFixed, PyDateTimeLocal, PyDateTimeTz, PyDateTimeUtc};
+use crate::errors::ChronoError;
+use crate::posix::PosixTz;
+use crate::stdlib;
+use crate::tzif::{TzifType, TzifZone};
+use crate::pickle;
 
-#[pyclass(name = "Utc")]
+#[pyclass(name = "Utc", extends = PyTzInfo)]
 #[derive(Clone, Copy)]
 pub struct PyUtc;
 
+pickle::immutable_pymethods! {
 #[pymethods]
 impl PyUtc {
     #[new]
@@ -12,6 +26,26 @@ impl PyUtc {
         PyUtc
     }
 
+    fn utcoffset<'py>(&self, dt: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+        stdlib::tz_utcoffset(&AnyZone::Utc, dt)
+    }
+
+    fn dst<'py>(&self, dt: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+        stdlib::tz_dst(&AnyZone::Utc, dt)
+    }
+
+    fn tzname(&self, dt: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+        stdlib::tz_tzname(&AnyZone::Utc, dt)
+    }
+
+    fn fromutc<'py>(slf: &Bound<'py, Self>, dt: &Bound<'py, PyAny>) -> PyResult<Bound<'py, StdDateTime>> {
+        stdlib::tz_fromutc(&AnyZone::Utc, slf.as_any(), dt)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "Utc", ())
+    }
+
     fn __str__(&self) -> String {
         "UTC".to_string()
     }
@@ -19,12 +53,28 @@ impl PyUtc {
     fn __repr__(&self) -> String {
         "Utc()".to_string()
     }
+
+    fn __eq__(&self, _other: &Self) -> bool {
+        true
+    }
+
+    fn __ne__(&self, _other: &Self) -> bool {
+        false
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        "Utc".hash(&mut hasher);
+        hasher.finish()
+    }
+}
 }
 
-#[pyclass(name = "Local")]
+#[pyclass(name = "Local", extends = PyTzInfo)]
 #[derive(Clone, Copy)]
 pub struct PyLocal;
 
+pickle::immutable_pymethods! {
 #[pymethods]
 impl PyLocal {
     #[new]
@@ -32,6 +82,26 @@ impl PyLocal {
         PyLocal
     }
 
+    fn utcoffset<'py>(&self, dt: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+        stdlib::tz_utcoffset(&AnyZone::Local, dt)
+    }
+
+    fn dst<'py>(&self, dt: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+        stdlib::tz_dst(&AnyZone::Local, dt)
+    }
+
+    fn tzname(&self, dt: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+        stdlib::tz_tzname(&AnyZone::Local, dt)
+    }
+
+    fn fromutc<'py>(slf: &Bound<'py, Self>, dt: &Bound<'py, PyAny>) -> PyResult<Bound<'py, StdDateTime>> {
+        stdlib::tz_fromutc(&AnyZone::Local, slf.as_any(), dt)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "Local", ())
+    }
+
     fn __str__(&self) -> String {
         "Local".to_string()
     }
@@ -39,45 +109,59 @@ impl PyLocal {
     fn __repr__(&self) -> String {
         "Local()".to_string()
     }
+
+    fn __eq__(&self, _other: &Self) -> bool {
+        true
+    }
+
+    fn __ne__(&self, _other: &Self) -> bool {
+        false
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        "Local".hash(&mut hasher);
+        hasher.finish()
+    }
+}
 }
 
-#[pyclass(name = "FixedOffset")]
+#[pyclass(name = "FixedOffset", extends = PyTzInfo)]
 #[derive(Clone, Copy)]
 pub struct PyFixedOffset {
     pub inner: FixedOffset,
 }
 
+pickle::immutable_pymethods! {
 #[pymethods]
 impl PyFixedOffset {
     #[new]
-    fn new(seconds: i32) -> Self {
-        PyFixedOffset {
-            inner: FixedOffset::east_opt(seconds).unwrap_or(FixedOffset::east_opt(0).unwrap()),
-        }
+    fn new(seconds: i32) -> PyResult<Self> {
+        FixedOffset::east_opt(seconds)
+            .map(|inner| PyFixedOffset { inner })
+            .ok_or_else(|| ChronoError::OutOfRange("Invalid offset".to_string()).into())
     }
 
     #[staticmethod]
-    fn east(seconds: i32) -> PyResult<Self> {
-        FixedOffset::east_opt(seconds)
-            .map(|inner| PyFixedOffset { inner })
-            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("Invalid offset"))
+    fn east(py: Python, seconds: i32) -> PyResult<Py<Self>> {
+        Py::new(py, Self::new(seconds)?)
     }
 
     #[staticmethod]
-    fn east_opt(seconds: i32) -> Option<Self> {
-        FixedOffset::east_opt(seconds).map(|inner| PyFixedOffset { inner })
+    fn east_opt(py: Python, seconds: i32) -> PyResult<Option<Py<Self>>> {
+        FixedOffset::east_opt(seconds).map(|inner| Py::new(py, PyFixedOffset { inner })).transpose()
     }
 
     #[staticmethod]
-    fn west(seconds: i32) -> PyResult<Self> {
-        FixedOffset::west_opt(seconds)
-            .map(|inner| PyFixedOffset { inner })
-            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("Invalid offset"))
+    fn west(py: Python, seconds: i32) -> PyResult<Py<Self>> {
+        let inner = FixedOffset::west_opt(seconds)
+            .ok_or_else(|| ChronoError::OutOfRange("Invalid offset".to_string()))?;
+        Py::new(py, PyFixedOffset { inner })
     }
 
     #[staticmethod]
-    fn west_opt(seconds: i32) -> Option<Self> {
-        FixedOffset::west_opt(seconds).map(|inner| PyFixedOffset { inner })
+    fn west_opt(py: Python, seconds: i32) -> PyResult<Option<Py<Self>>> {
+        FixedOffset::west_opt(seconds).map(|inner| Py::new(py, PyFixedOffset { inner })).transpose()
     }
 
     fn local_minus_utc(&self) -> i32 {
@@ -88,6 +172,26 @@ impl PyFixedOffset {
         self.inner.utc_minus_local()
     }
 
+    fn utcoffset<'py>(&self, dt: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+        stdlib::tz_utcoffset(&AnyZone::Fixed(self.inner), dt)
+    }
+
+    fn dst<'py>(&self, dt: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+        stdlib::tz_dst(&AnyZone::Fixed(self.inner), dt)
+    }
+
+    fn tzname(&self, dt: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+        stdlib::tz_tzname(&AnyZone::Fixed(self.inner), dt)
+    }
+
+    fn fromutc<'py>(slf: &Bound<'py, Self>, dt: &Bound<'py, PyAny>) -> PyResult<Bound<'py, StdDateTime>> {
+        stdlib::tz_fromutc(&AnyZone::Fixed(slf.borrow().inner), slf.as_any(), dt)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "FixedOffset", (self.inner.local_minus_utc(),))
+    }
+
     fn __str__(&self) -> String {
         format!("{:+}", self.inner)
     }
@@ -108,3 +212,605 @@ impl PyFixedOffset {
         self.inner.local_minus_utc() as u64
     }
 }
+}
+
+/// A time zone with varying offsets, backed by chrono-tz's compiled
+/// database, rules loaded from a TZif file at runtime, or a POSIX TZ string.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Zone {
+    Iana(Tz),
+    Tzif(Arc<TzifZone>),
+    Posix(Arc<PosixTz>),
+}
+
+/// Sampling interval used to find transitions in chrono-tz zones, whose
+/// span tables are not public. Pairs of transitions that cancel out within
+/// one step would not be reported; the shortest-lived offset in the bundled
+/// database (America/Noronha's week of DST in October 2000) lasts 167 hours.
+const IANA_SCAN_STEP_SECS: i64 = 24 * 3600;
+
+/// `Tz.transitions` and `PosixTz.transitions` only search instants from
+/// 1800-01-01 to 2200-01-01 UTC, which covers every transition in the
+/// bundled database and keeps the IANA scan and POSIX rules bounded.
+const TRANSITIONS_MIN: i64 = -5_364_662_400;
+const TRANSITIONS_MAX: i64 = 7_258_118_400;
+
+impl Zone {
+    pub fn name(&self) -> &str {
+        match self {
+            Zone::Iana(tz) => tz.name(),
+            Zone::Tzif(zone) => &zone.name,
+            Zone::Posix(zone) => &zone.source,
+        }
+    }
+
+    /// Seconds of daylight saving time included in the offset at a UTC
+    /// timestamp.
+    pub fn dst_at(&self, timestamp: i64) -> i32 {
+        match self {
+            Zone::Iana(tz) => DateTime::from_timestamp(timestamp, 0).map_or(0, |utc| {
+                tz.offset_from_utc_datetime(&utc.naive_utc()).dst_offset().num_seconds() as i32
+            }),
+            Zone::Tzif(zone) => zone.dst_at(timestamp),
+            Zone::Posix(zone) => zone.dst_savings(zone.is_dst_at_utc(timestamp)),
+        }
+    }
+
+    pub fn offset_at(&self, timestamp: i64) -> Option<ZoneOffset> {
+        DateTime::from_timestamp(timestamp, 0).map(|utc| self.offset_from_utc_datetime(&utc.naive_utc()))
+    }
+
+    /// Offset changes in `[start, end)`, as (UTC timestamp, offset before,
+    /// offset after), in chronological order.
+    pub fn transitions(&self, start: i64, end: i64) -> Vec<(i64, ZoneOffset, ZoneOffset)> {
+        let candidates = match self {
+            Zone::Iana(_) => self.scan_transitions(start, end),
+            Zone::Tzif(zone) => {
+                let mut candidates: Vec<i64> = zone.transitions.iter().copied()
+                    .filter(|t| (start..end).contains(t))
+                    .collect();
+                if let (Some(footer), Some(&last)) = (&zone.footer, zone.transitions.last()) {
+                    candidates.extend(footer_transitions(footer, last.max(start), end).filter(|&t| t > last));
+                }
+                candidates
+            }
+            Zone::Posix(zone) => footer_transitions(zone, start, end).collect(),
+        };
+
+        candidates.into_iter()
+            .filter_map(|at| {
+                let before = self.offset_at(at - 1)?;
+                let after = self.offset_at(at)?;
+                (!before.same_as(&after)).then_some((at, before, after))
+            })
+            .collect()
+    }
+
+    fn scan_transitions(&self, start: i64, end: i64) -> Vec<i64> {
+        let mut found = Vec::new();
+        let mut prev = start - 1;
+        let Some(mut offset) = self.offset_at(prev) else {
+            return found;
+        };
+        while prev < end - 1 {
+            let next = (prev + IANA_SCAN_STEP_SECS).min(end - 1);
+            let Some(next_offset) = self.offset_at(next) else { break };
+            if next_offset.same_as(&offset) {
+                prev = next;
+                continue;
+            }
+            // The offset changed somewhere in (prev, next]: narrow it down to
+            // the first second with a different offset, then keep scanning
+            // from there in case several changes share the interval.
+            let (mut lo, mut hi) = (prev, next);
+            while hi - lo > 1 {
+                let mid = lo + (hi - lo) / 2;
+                match self.offset_at(mid) {
+                    Some(mid_offset) if mid_offset.same_as(&offset) => lo = mid,
+                    _ => hi = mid,
+                }
+            }
+            found.push(hi);
+            prev = hi;
+            offset = match self.offset_at(hi) {
+                Some(offset) => offset,
+                None => break,
+            };
+        }
+        found
+    }
+}
+
+/// DST transitions of a POSIX rule in `[start, end)`.
+fn footer_transitions(zone: &PosixTz, start: i64, end: i64) -> impl Iterator<Item = i64> + '_ {
+    let year = |ts: i64| DateTime::from_timestamp(ts, 0).map_or(1970, |dt| dt.year());
+    (year(start) - 1..=year(end) + 1)
+        .flat_map(move |y| zone.transitions_in_year(y).into_iter().flatten())
+        .map(|(at, _)| at)
+        .filter(move |t| (start..end).contains(t))
+}
+
+/// The offset of a [`Zone`] at a given instant, along with its DST flag and
+/// abbreviation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ZoneOffset {
+    Iana(chrono_tz::TzOffset),
+    Tzif(Arc<TzifZone>, TzifType),
+    Posix(Arc<PosixTz>, bool),
+}
+
+impl ZoneOffset {
+    /// Whether two offsets agree on UTC offset, DST flag and abbreviation.
+    pub fn same_as(&self, other: &ZoneOffset) -> bool {
+        self.fix() == other.fix() && self.is_dst() == other.is_dst() && self.to_string() == other.to_string()
+    }
+
+    pub fn is_dst(&self) -> bool {
+        match self {
+            ZoneOffset::Iana(offset) => !offset.dst_offset().is_zero(),
+            ZoneOffset::Tzif(zone, ty) => zone.local_type(*ty).is_dst,
+            ZoneOffset::Posix(_, is_dst) => *is_dst,
+        }
+    }
+}
+
+impl Offset for ZoneOffset {
+    fn fix(&self) -> FixedOffset {
+        match self {
+            ZoneOffset::Iana(offset) => offset.fix(),
+            ZoneOffset::Tzif(zone, ty) => zone.local_type(*ty).offset,
+            ZoneOffset::Posix(zone, is_dst) => zone.local_type(*is_dst).offset,
+        }
+    }
+}
+
+impl fmt::Display for ZoneOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZoneOffset::Iana(offset) => write!(f, "{}", offset),
+            ZoneOffset::Tzif(zone, ty) => f.write_str(&zone.local_type(*ty).abbreviation),
+            ZoneOffset::Posix(zone, is_dst) => f.write_str(&zone.local_type(*is_dst).abbreviation),
+        }
+    }
+}
+
+impl TimeZone for Zone {
+    type Offset = ZoneOffset;
+
+    fn from_offset(offset: &ZoneOffset) -> Self {
+        match offset {
+            ZoneOffset::Iana(offset) => Zone::Iana(Tz::from_offset(offset)),
+            ZoneOffset::Tzif(zone, _) => Zone::Tzif(zone.clone()),
+            ZoneOffset::Posix(zone, _) => Zone::Posix(zone.clone()),
+        }
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<ZoneOffset> {
+        self.offset_from_local_datetime(&local.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<ZoneOffset> {
+        match self {
+            Zone::Iana(tz) => tz.offset_from_local_datetime(local).map(ZoneOffset::Iana),
+            Zone::Tzif(zone) => zone.types_at_local(local.and_utc().timestamp())
+                .map(|ty| ZoneOffset::Tzif(zone.clone(), ty)),
+            Zone::Posix(zone) => zone.types_at_local(local.and_utc().timestamp())
+                .map(|is_dst| ZoneOffset::Posix(zone.clone(), is_dst)),
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> ZoneOffset {
+        self.offset_from_utc_datetime(&utc.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> ZoneOffset {
+        match self {
+            Zone::Iana(tz) => ZoneOffset::Iana(tz.offset_from_utc_datetime(utc)),
+            Zone::Tzif(zone) => ZoneOffset::Tzif(zone.clone(), zone.type_at_utc(utc.and_utc().timestamp())),
+            Zone::Posix(zone) => ZoneOffset::Posix(zone.clone(), zone.is_dst_at_utc(utc.and_utc().timestamp())),
+        }
+    }
+}
+
+#[pyclass(name = "Tz", extends = PyTzInfo)]
+#[derive(Clone)]
+pub struct PyTz {
+    pub inner: Zone,
+}
+
+pickle::immutable_pymethods! {
+#[pymethods]
+impl PyTz {
+    #[new]
+    fn new(name: &str) -> PyResult<Self> {
+        name.parse::<Tz>()
+            .map(|tz| PyTz { inner: Zone::Iana(tz) })
+            .map_err(|_| ChronoError::InvalidTimezone(format!("Unknown time zone '{}'", name)).into())
+    }
+
+    #[staticmethod]
+    fn utc(py: Python) -> PyResult<Py<Self>> {
+        Py::new(py, PyTz { inner: Zone::Iana(Tz::UTC) })
+    }
+
+    #[staticmethod]
+    fn from_file(py: Python, path: PathBuf) -> PyResult<Py<Self>> {
+        let zone = TzifZone::from_file(&path)?;
+        Py::new(py, PyTz { inner: Zone::Tzif(Arc::new(zone)) })
+    }
+
+    #[staticmethod]
+    fn from_system(py: Python, name: &str) -> PyResult<Py<Self>> {
+        let zone = TzifZone::from_system(name)?;
+        Py::new(py, PyTz { inner: Zone::Tzif(Arc::new(zone)) })
+    }
+
+    #[staticmethod]
+    fn available() -> Vec<&'static str> {
+        TZ_VARIANTS.iter().map(|tz| tz.name()).collect()
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    /// Offset changes from `start` up to `end`, in order. Both must lie
+    /// between 1800-01-01 and 2200-01-01 UTC, or `OutOfRangeError` is raised.
+    fn transitions(&self, start: &PyDateTime, end: &PyDateTime) -> PyResult<Vec<PyTransition>> {
+        PyTransition::between(&self.inner, start, end)
+    }
+
+    fn utcoffset<'py>(&self, dt: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+        stdlib::tz_utcoffset(&AnyZone::Zone(self.inner.clone()), dt)
+    }
+
+    fn dst<'py>(&self, dt: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+        stdlib::tz_dst(&AnyZone::Zone(self.inner.clone()), dt)
+    }
+
+    fn tzname(&self, dt: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+        stdlib::tz_tzname(&AnyZone::Zone(self.inner.clone()), dt)
+    }
+
+    fn fromutc<'py>(slf: &Bound<'py, Self>, dt: &Bound<'py, PyAny>) -> PyResult<Bound<'py, StdDateTime>> {
+        stdlib::tz_fromutc(&AnyZone::Zone(slf.borrow().inner.clone()), slf.as_any(), dt)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "Tz", pickle::zone_state(py, &self.inner))
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Tz('{}')", self.inner.name())
+    }
+
+    /// Zones compare equal only with the same backend and rules, so
+    /// `Tz.from_system("Europe/Berlin") != Tz("Europe/Berlin")`.
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __ne__(&self, other: &Self) -> bool {
+        self.inner != other.inner
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        std::mem::discriminant(&self.inner).hash(&mut hasher);
+        self.inner.name().hash(&mut hasher);
+        hasher.finish()
+    }
+}
+}
+
+#[pyclass(name = "PosixTz", extends = PyTzInfo)]
+#[derive(Clone)]
+pub struct PyPosixTz {
+    pub inner: Arc<PosixTz>,
+}
+
+pickle::immutable_pymethods! {
+#[pymethods]
+impl PyPosixTz {
+    #[new]
+    fn new(rule: &str) -> PyResult<Self> {
+        Ok(PyPosixTz { inner: Arc::new(PosixTz::parse(rule)?) })
+    }
+
+    fn std_abbreviation(&self) -> &str {
+        &self.inner.std.abbreviation
+    }
+
+    fn std_offset_seconds(&self) -> i32 {
+        self.inner.std.offset.local_minus_utc()
+    }
+
+    fn dst_abbreviation(&self) -> Option<&str> {
+        self.inner.rule.as_ref().map(|rule| rule.dst.abbreviation.as_str())
+    }
+
+    fn dst_offset_seconds(&self) -> Option<i32> {
+        self.inner.rule.as_ref().map(|rule| rule.dst.offset.local_minus_utc())
+    }
+
+    fn has_dst(&self) -> bool {
+        self.inner.rule.is_some()
+    }
+
+    fn to_tz(&self, py: Python) -> PyResult<Py<PyTz>> {
+        Py::new(py, PyTz { inner: Zone::Posix(self.inner.clone()) })
+    }
+
+    /// See `Tz.transitions`.
+    fn transitions(&self, start: &PyDateTime, end: &PyDateTime) -> PyResult<Vec<PyTransition>> {
+        PyTransition::between(&Zone::Posix(self.inner.clone()), start, end)
+    }
+
+    fn utcoffset<'py>(&self, dt: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+        stdlib::tz_utcoffset(&AnyZone::Zone(Zone::Posix(self.inner.clone())), dt)
+    }
+
+    fn dst<'py>(&self, dt: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyDelta>>> {
+        stdlib::tz_dst(&AnyZone::Zone(Zone::Posix(self.inner.clone())), dt)
+    }
+
+    fn tzname(&self, dt: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+        stdlib::tz_tzname(&AnyZone::Zone(Zone::Posix(self.inner.clone())), dt)
+    }
+
+    fn fromutc<'py>(slf: &Bound<'py, Self>, dt: &Bound<'py, PyAny>) -> PyResult<Bound<'py, StdDateTime>> {
+        stdlib::tz_fromutc(&AnyZone::Zone(Zone::Posix(slf.borrow().inner.clone())), slf.as_any(), dt)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "PosixTz", (self.inner.source.as_str(),))
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.source.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PosixTz('{}')", self.inner.source)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+
+    fn __ne__(&self, other: &Self) -> bool {
+        self.inner != other.inner
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.inner.source.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+}
+
+/// A change of UTC offset, DST flag or abbreviation in a time zone.
+#[pyclass(name = "Transition")]
+#[derive(Clone, PartialEq)]
+pub struct PyTransition {
+    pub at: DateTime<Utc>,
+    pub before: ZoneOffset,
+    pub after: ZoneOffset,
+}
+
+impl PyTransition {
+    fn between(zone: &Zone, start: &PyDateTime, end: &PyDateTime) -> PyResult<Vec<PyTransition>> {
+        // Transitions fall on whole seconds, so round both bounds up to one.
+        let ceil = |dt: &DateTime<Utc>| dt.timestamp() + i64::from(dt.timestamp_subsec_nanos() > 0);
+        let (start, end) = (ceil(&start.utc), ceil(&end.utc));
+        let bounds = TRANSITIONS_MIN..=TRANSITIONS_MAX;
+        if !bounds.contains(&start) || !bounds.contains(&end) {
+            return Err(ChronoError::OutOfRange(
+                "Transitions are only searched between 1800-01-01 and 2200-01-01".to_string()
+            ).into());
+        }
+        Ok(zone.transitions(start, end)
+            .into_iter()
+            .filter_map(|(at, before, after)| {
+                DateTime::from_timestamp(at, 0).map(|at| PyTransition { at, before, after })
+            })
+            .collect())
+    }
+}
+
+pickle::immutable_pymethods! {
+#[pymethods]
+impl PyTransition {
+    fn instant(&self) -> PyDateTimeUtc {
+        PyDateTimeUtc { inner: self.at }
+    }
+
+    fn offset_before(&self) -> i32 {
+        self.before.fix().local_minus_utc()
+    }
+
+    fn offset_after(&self) -> i32 {
+        self.after.fix().local_minus_utc()
+    }
+
+    fn abbreviation_before(&self) -> String {
+        self.before.to_string()
+    }
+
+    fn abbreviation(&self) -> String {
+        self.after.to_string()
+    }
+
+    fn is_dst(&self) -> bool {
+        self.after.is_dst()
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        let zone = Zone::from_offset(&self.after);
+        pickle::reduce(py, "Transition", (self.at.timestamp(), pickle::zone_state(py, &zone)))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Transition({}, {} -> {}, {}{})",
+            self.at.to_rfc3339(),
+            self.before.fix(),
+            self.after.fix(),
+            self.after,
+            if self.after.is_dst() { ", dst" } else { "" },
+        )
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn __ne__(&self, other: &Self) -> bool {
+        self != other
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.at.hash(&mut hasher);
+        self.before.fix().hash(&mut hasher);
+        self.after.fix().hash(&mut hasher);
+        self.after.to_string().hash(&mut hasher);
+        hasher.finish()
+    }
+}
+}
+
+/// Any of the time zone classes, for conversions that accept every kind of
+/// zone.
+#[derive(Clone)]
+pub enum AnyZone {
+    Utc,
+    Local,
+    Fixed(FixedOffset),
+    Zone(Zone),
+}
+
+impl AnyZone {
+    pub fn from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<DateTime<Utc>> {
+        match self {
+            AnyZone::Utc => LocalResult::Single(local.and_utc()),
+            AnyZone::Local => Local.from_local_datetime(local).map(|dt| dt.to_utc()),
+            AnyZone::Fixed(offset) => offset.from_local_datetime(local).map(|dt| dt.to_utc()),
+            AnyZone::Zone(zone) => zone.from_local_datetime(local).map(|dt| dt.to_utc()),
+        }
+    }
+
+    pub fn naive_local(&self, utc: &DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            AnyZone::Utc => utc.naive_utc(),
+            AnyZone::Local => utc.with_timezone(&Local).naive_local(),
+            AnyZone::Fixed(offset) => utc.with_timezone(offset).naive_local(),
+            AnyZone::Zone(zone) => utc.with_timezone(zone).naive_local(),
+        }
+    }
+
+    pub fn offset_at(&self, utc: &DateTime<Utc>) -> FixedOffset {
+        match self {
+            AnyZone::Utc => Utc.fix(),
+            AnyZone::Local => *utc.with_timezone(&Local).offset(),
+            AnyZone::Fixed(offset) => *offset,
+            AnyZone::Zone(zone) => utc.with_timezone(zone).offset().fix(),
+        }
+    }
+
+    /// Seconds of daylight saving time included in the offset at `utc`.
+    pub fn dst_at(&self, utc: &DateTime<Utc>) -> i32 {
+        match self {
+            AnyZone::Utc | AnyZone::Fixed(_) => 0,
+            AnyZone::Local => {
+                // chrono only exposes the system zone's total offset, so take
+                // the smaller of the January and July offsets as standard time
+                // (which covers both hemispheres).
+                let offset = self.offset_at(utc).local_minus_utc();
+                let year = utc.with_timezone(&Local).year();
+                let standard = [1, 7].into_iter()
+                    .filter_map(|month| NaiveDate::from_ymd_opt(year, month, 1))
+                    .map(|date| Local.offset_from_utc_date(&date).local_minus_utc())
+                    .min()
+                    .unwrap_or(offset);
+                offset - standard
+            }
+            AnyZone::Zone(zone) => zone.dst_at(utc.timestamp()),
+        }
+    }
+
+    pub fn abbreviation(&self, utc: &DateTime<Utc>) -> String {
+        match self {
+            AnyZone::Utc => "UTC".to_string(),
+            AnyZone::Zone(zone) => utc.with_timezone(zone).offset().to_string(),
+            AnyZone::Local | AnyZone::Fixed(_) => self.offset_at(utc).to_string(),
+        }
+    }
+
+    /// Wraps a UTC instant in the aware datetime class matching this zone.
+    pub fn wrap(&self, py: Python, utc: DateTime<Utc>) -> PyObject {
+        match self {
+            AnyZone::Utc => PyDateTimeUtc { inner: utc }.into_py(py),
+            AnyZone::Local => PyDateTimeLocal { inner: utc.with_timezone(&Local) }.into_py(py),
+            AnyZone::Fixed(offset) => PyDateTimeFixed { inner: utc.with_timezone(offset) }.into_py(py),
+            AnyZone::Zone(zone) => PyDateTimeTz { inner: utc.with_timezone(zone) }.into_py(py),
+        }
+    }
+}
+
+impl<'py> FromPyObject<'py> for AnyZone {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if ob.is_instance_of::<PyUtc>() {
+            Ok(AnyZone::Utc)
+        } else if ob.is_instance_of::<PyLocal>() {
+            Ok(AnyZone::Local)
+        } else if let Ok(offset) = ob.downcast::<PyFixedOffset>() {
+            Ok(AnyZone::Fixed(offset.borrow().inner))
+        } else if let Ok(tz) = ob.downcast::<PyTz>() {
+            Ok(AnyZone::Zone(tz.borrow().inner.clone()))
+        } else if let Ok(tz) = ob.downcast::<PyPosixTz>() {
+            Ok(AnyZone::Zone(Zone::Posix(tz.borrow().inner.clone())))
+        } else {
+            Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "Expected Utc, Local, FixedOffset, Tz or PosixTz"
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32) -> i64 {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, 0, 0).unwrap().and_utc().timestamp()
+    }
+
+    #[test]
+    fn finds_short_lived_iana_offsets() {
+        // Fernando de Noronha observed DST for a single week in October 2000.
+        let zone = Zone::Iana(chrono_tz::America::Noronha);
+        let found: Vec<_> = zone.transitions(utc(2000, 6, 1, 0), utc(2001, 1, 1, 0))
+            .into_iter()
+            .map(|(at, before, after)| (at, before.fix().local_minus_utc(), after.fix().local_minus_utc()))
+            .collect();
+        assert_eq!(found, [
+            (utc(2000, 10, 8, 2), -7_200, -3_600),
+            (utc(2000, 10, 15, 1), -3_600, -7_200),
+        ]);
+    }
+
+    #[test]
+    fn finds_transitions_at_scan_bounds() {
+        let zone = Zone::Iana(chrono_tz::America::New_York);
+        let start = utc(2024, 3, 10, 7);
+        assert_eq!(zone.transitions(start, start + 1).len(), 1);
+        assert!(zone.transitions(start + 1, utc(2024, 11, 3, 6)).is_empty());
+        assert_eq!(zone.transitions(TRANSITIONS_MIN, TRANSITIONS_MAX).last().map(|t| t.0), Some(utc(2099, 11, 1, 6)));
+    }
+}
@@ -0,0 +1,462 @@
+//! Reader for compiled TZif time zone files (RFC 8536), as found under
+//! `/usr/share/zoneinfo` on most Unix systems.
+
+use std::path::{Component, Path, PathBuf};
+use chrono::{FixedOffset, LocalResult};
+use crate::errors::ChronoError;
+use crate::posix::PosixTz;
+
+const ZONEINFO_DIRS: &[&str] = &[
+    "/usr/share/zoneinfo",
+    "/usr/lib/zoneinfo",
+    "/usr/share/lib/zoneinfo",
+    "/etc/zoneinfo",
+];
+
+/// Widest offset span we have to look around a local time to find every
+/// period that could contain it.
+const MAX_OFFSET_SECS: i64 = 86_400;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalTimeType {
+    pub offset: FixedOffset,
+    pub is_dst: bool,
+    pub abbreviation: String,
+}
+
+/// Where a [`TzifZone`] local time type comes from: its type table, or the
+/// standard/DST half of the POSIX footer that governs instants after the
+/// last transition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TzifType {
+    Table(usize),
+    Footer(bool),
+}
+
+/// A zone decoded from TZif data: UTC transition instants and the local
+/// time type that applies from each of them onwards.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TzifZone {
+    pub name: String,
+    pub transitions: Vec<i64>,
+    pub transition_types: Vec<usize>,
+    pub types: Vec<LocalTimeType>,
+    pub footer: Option<PosixTz>,
+}
+
+impl TzifZone {
+    pub fn from_file(path: &Path) -> Result<Self, ChronoError> {
+        let name = path.display().to_string();
+        let data = std::fs::read(path)
+            .map_err(|e| ChronoError::InvalidTimezone(format!("Cannot read TZif file '{}': {}", name, e)))?;
+        Self::parse(&name, &data)
+    }
+
+    /// Looks `name` up in `$TZDIR` and the usual zoneinfo directories.
+    pub fn from_system(name: &str) -> Result<Self, ChronoError> {
+        let relative = Path::new(name);
+        if name.is_empty() || !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+            return Err(ChronoError::InvalidTimezone(format!("Invalid time zone name '{}'", name)));
+        }
+
+        let tzdir = std::env::var_os("TZDIR").map(PathBuf::from);
+        let candidates = tzdir.into_iter().chain(ZONEINFO_DIRS.iter().map(PathBuf::from));
+        for dir in candidates {
+            let path = dir.join(relative);
+            if let Ok(data) = std::fs::read(&path) {
+                return Self::parse(name, &data);
+            }
+        }
+        Err(ChronoError::InvalidTimezone(format!("Time zone '{}' not found in the system zoneinfo", name)))
+    }
+
+    pub fn parse(name: &str, data: &[u8]) -> Result<Self, ChronoError> {
+        let invalid = |msg: &str| ChronoError::InvalidTimezone(format!("{}: {}", name, msg));
+
+        let mut reader = Reader { data, pos: 0 };
+        let header = Header::read(&mut reader).ok_or_else(|| invalid("truncated header"))?
+            .map_err(&invalid)?;
+
+        let (header, time_size) = if header.version >= 2 {
+            reader.skip(header.body_len(4)).ok_or_else(|| invalid("truncated v1 data block"))?;
+            let v2 = Header::read(&mut reader).ok_or_else(|| invalid("truncated v2 header"))?
+                .map_err(&invalid)?;
+            (v2, 8)
+        } else {
+            (header, 4)
+        };
+
+        let body = reader.take(header.body_len(time_size)).ok_or_else(|| invalid("truncated data block"))?;
+        let mut body = Reader { data: body, pos: 0 };
+
+        let mut transitions = Vec::with_capacity(header.timecnt);
+        for _ in 0..header.timecnt {
+            let t = if time_size == 8 { body.i64() } else { body.i32().map(i64::from) };
+            transitions.push(t.ok_or_else(|| invalid("truncated transition times"))?);
+        }
+        if transitions.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(invalid("transition times are not in ascending order"));
+        }
+
+        let mut transition_types = Vec::with_capacity(header.timecnt);
+        for _ in 0..header.timecnt {
+            let idx = body.u8().ok_or_else(|| invalid("truncated transition types"))? as usize;
+            if idx >= header.typecnt {
+                return Err(invalid("transition refers to an undefined local time type"));
+            }
+            transition_types.push(idx);
+        }
+
+        let mut raw_types = Vec::with_capacity(header.typecnt);
+        for _ in 0..header.typecnt {
+            let utoff = body.i32().ok_or_else(|| invalid("truncated local time types"))?;
+            let isdst = body.u8().ok_or_else(|| invalid("truncated local time types"))?;
+            let desigidx = body.u8().ok_or_else(|| invalid("truncated local time types"))? as usize;
+            raw_types.push((utoff, isdst, desigidx));
+        }
+
+        let chars = body.take(header.charcnt).ok_or_else(|| invalid("truncated abbreviations"))?;
+        let mut types = Vec::with_capacity(header.typecnt);
+        for (utoff, isdst, desigidx) in raw_types {
+            let offset = FixedOffset::east_opt(utoff)
+                .ok_or_else(|| invalid("UTC offset out of range"))?;
+            if isdst > 1 {
+                return Err(invalid("DST indicator must be 0 or 1"));
+            }
+            let abbreviation = chars.get(desigidx..)
+                .and_then(|rest| rest.iter().position(|&b| b == 0).map(|end| &rest[..end]))
+                .ok_or_else(|| invalid("abbreviation index out of range"))?;
+            let abbreviation = std::str::from_utf8(abbreviation)
+                .map_err(|_| invalid("abbreviation is not valid ASCII"))?
+                .to_string();
+            types.push(LocalTimeType { offset, is_dst: isdst == 1, abbreviation });
+        }
+
+        let footer = if header.version >= 2 {
+            let rest = &data[reader.pos..];
+            if rest.first() != Some(&b'\n') {
+                return Err(invalid("missing footer"));
+            }
+            let end = rest[1..].iter().position(|&b| b == b'\n')
+                .ok_or_else(|| invalid("unterminated footer"))?;
+            let footer = std::str::from_utf8(&rest[1..=end])
+                .map_err(|_| invalid("footer is not valid ASCII"))?;
+            if footer.is_empty() { None } else { Some(PosixTz::parse(footer)?) }
+        } else {
+            None
+        };
+
+        Ok(TzifZone {
+            name: name.to_string(),
+            transitions,
+            transition_types,
+            types,
+            footer,
+        })
+    }
+
+    pub fn local_type(&self, ty: TzifType) -> &LocalTimeType {
+        match (ty, &self.footer) {
+            (TzifType::Table(idx), _) => &self.types[idx],
+            (TzifType::Footer(is_dst), Some(footer)) => footer.local_type(is_dst),
+            (TzifType::Footer(_), None) => &self.types[0],
+        }
+    }
+
+    /// The local time type in effect at a UTC timestamp.
+    pub fn type_at_utc(&self, timestamp: i64) -> TzifType {
+        match (self.transitions.partition_point(|&t| t <= timestamp), &self.footer) {
+            (n, Some(footer)) if n == self.transitions.len() => TzifType::Footer(footer.is_dst_at_utc(timestamp)),
+            (0, _) => TzifType::Table(0),
+            (n, _) => TzifType::Table(self.transition_types[n - 1]),
+        }
+    }
+
+    /// Seconds of daylight saving time in effect at a UTC timestamp. TZif
+    /// only flags DST types, so the saving is measured against the nearest
+    /// standard time type in use before (or failing that, after) it.
+    pub fn dst_at(&self, timestamp: i64) -> i32 {
+        let ty = self.type_at_utc(timestamp);
+        let current = self.local_type(ty);
+        if !current.is_dst {
+            return 0;
+        }
+        if let (TzifType::Footer(_), Some(footer)) = (ty, &self.footer) {
+            return footer.dst_savings(true);
+        }
+
+        let period = self.transitions.partition_point(|&t| t <= timestamp);
+        let before = self.transition_types[..period.saturating_sub(1)].iter().rev().chain([&0]);
+        let after = self.transition_types[period..].iter();
+        before.chain(after)
+            .map(|&idx| &self.types[idx])
+            .chain(self.footer.as_ref().map(|footer| &footer.std))
+            .find(|ty| !ty.is_dst)
+            .map_or(3600, |std| current.offset.local_minus_utc() - std.offset.local_minus_utc())
+    }
+
+    /// The local time types that can produce a local timestamp, earliest
+    /// instant first.
+    pub fn types_at_local(&self, local: i64) -> LocalResult<TzifType> {
+        let first = self.transitions.partition_point(|&t| t <= local - MAX_OFFSET_SECS);
+        let last = self.transitions.partition_point(|&t| t <= local + MAX_OFFSET_SECS);
+
+        let mut matches = Vec::with_capacity(2);
+        for period in first..=last {
+            let start = if period == 0 { i64::MIN } else { self.transitions[period - 1] };
+            match (self.transitions.get(period), &self.footer) {
+                (None, Some(footer)) => {
+                    // Past the last transition (or throughout, if there are
+                    // none) the footer rules take over, but only for instants
+                    // after that transition.
+                    let candidates = match footer.types_at_local(local) {
+                        LocalResult::None => vec![],
+                        LocalResult::Single(is_dst) => vec![is_dst],
+                        LocalResult::Ambiguous(earliest, latest) => vec![earliest, latest],
+                    };
+                    for is_dst in candidates {
+                        let offset = i64::from(footer.local_type(is_dst).offset.local_minus_utc());
+                        if local - offset >= start {
+                            matches.push(TzifType::Footer(is_dst));
+                        }
+                    }
+                }
+                (end, _) => {
+                    let end = end.copied().unwrap_or(i64::MAX);
+                    let ty = if period == 0 { 0 } else { self.transition_types[period - 1] };
+                    let offset = i64::from(self.types[ty].offset.local_minus_utc());
+                    if start.saturating_add(offset) <= local && local < end.saturating_add(offset) {
+                        matches.push(TzifType::Table(ty));
+                    }
+                }
+            }
+        }
+
+        match matches[..] {
+            [] => LocalResult::None,
+            [ty] => LocalResult::Single(ty),
+            [earliest, .., latest] => LocalResult::Ambiguous(earliest, latest),
+        }
+    }
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    fn read(reader: &mut Reader) -> Option<Result<Self, &'static str>> {
+        let magic = reader.take(4)?;
+        let version = reader.u8()?;
+        reader.skip(15)?;
+        let mut counts = [0usize; 6];
+        for count in counts.iter_mut() {
+            *count = reader.u32()? as usize;
+        }
+        let [isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt] = counts;
+
+        if magic != b"TZif" {
+            return Some(Err("not a TZif file"));
+        }
+        let version = match version {
+            0 => 1,
+            b'2'..=b'4' => version - b'0',
+            _ => return Some(Err("unsupported TZif version")),
+        };
+        if typecnt == 0 || charcnt == 0 {
+            return Some(Err("no local time types defined"));
+        }
+        if (isutcnt != 0 && isutcnt != typecnt) || (isstdcnt != 0 && isstdcnt != typecnt) {
+            return Some(Err("inconsistent header counts"));
+        }
+
+        Some(Ok(Header { version, isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt }))
+    }
+
+    fn body_len(&self, time_size: usize) -> usize {
+        self.timecnt * (time_size + 1)
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    fn skip(&mut self, len: usize) -> Option<()> {
+        self.take(len).map(|_| ())
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4).map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        self.u32().map(|v| v as i32)
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        self.take(8).map(|b| i64::from_be_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    const NEW_YORK_RULE: &str = "EST5EDT,M3.2.0,M11.1.0";
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> i64 {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(h, min, 0).unwrap().and_utc().timestamp()
+    }
+
+    /// Encodes a TZif file. Version 1 writes only the 32-bit block; later
+    /// versions repeat the data in a 64-bit block followed by the footer.
+    fn tzif(version: u8, transitions: &[(i64, u8)], types: &[(i32, bool, &str)], footer: &str) -> Vec<u8> {
+        let mut chars = Vec::new();
+        let mut records = Vec::new();
+        for &(utoff, isdst, abbreviation) in types {
+            records.extend(utoff.to_be_bytes());
+            records.extend([u8::from(isdst), chars.len() as u8]);
+            chars.extend(abbreviation.bytes().chain([0]));
+        }
+        let block = |time_size: usize| {
+            let mut out = b"TZif".to_vec();
+            out.push(if version == 1 { 0 } else { b'0' + version });
+            out.extend([0; 15]);
+            for count in [0, 0, 0, transitions.len(), types.len(), chars.len()] {
+                out.extend((count as u32).to_be_bytes());
+            }
+            for &(at, _) in transitions {
+                out.extend(if time_size == 8 { at.to_be_bytes().to_vec() } else { (at as i32).to_be_bytes().to_vec() });
+            }
+            out.extend(transitions.iter().map(|&(_, idx)| idx));
+            out.extend(&records);
+            out.extend(&chars);
+            out
+        };
+        let mut data = block(4);
+        if version >= 2 {
+            data.extend(block(8));
+            data.extend(format!("\n{}\n", footer).bytes());
+        }
+        data
+    }
+
+    /// New York from its first standard time transition, through a year of
+    /// the 2007 rules, to the footer.
+    fn new_york() -> TzifZone {
+        let types = [(-17_762, false, "LMT"), (-18_000, false, "EST"), (-14_400, true, "EDT")];
+        let transitions = [(-2_717_650_800, 1), (utc(2007, 3, 11, 7, 0), 2), (utc(2007, 11, 4, 6, 0), 1)];
+        TzifZone::parse("New_York", &tzif(2, &transitions, &types, NEW_YORK_RULE)).unwrap()
+    }
+
+    #[test]
+    fn parses_types_and_footer() {
+        let zone = new_york();
+        assert_eq!(zone.transitions.len(), 3);
+        assert_eq!(zone.transition_types, [1, 2, 1]);
+        assert_eq!(zone.types[2].abbreviation, "EDT");
+        assert!(zone.types[2].is_dst);
+        assert_eq!(zone.footer, Some(PosixTz::parse(NEW_YORK_RULE).unwrap()));
+    }
+
+    #[test]
+    fn type_at_utc_uses_table_then_footer() {
+        let zone = new_york();
+        assert_eq!(zone.type_at_utc(-2_717_650_801), TzifType::Table(0));
+        assert_eq!(zone.type_at_utc(-2_717_650_800), TzifType::Table(1));
+        assert_eq!(zone.type_at_utc(utc(2007, 7, 1, 0, 0)), TzifType::Table(2));
+        assert_eq!(zone.type_at_utc(utc(2024, 1, 1, 0, 0)), TzifType::Footer(false));
+        assert_eq!(zone.type_at_utc(utc(2024, 7, 1, 0, 0)), TzifType::Footer(true));
+        assert_eq!(zone.local_type(TzifType::Footer(true)).abbreviation, "EDT");
+        assert_eq!(zone.dst_at(utc(2007, 7, 1, 0, 0)), 3_600);
+        assert_eq!(zone.dst_at(utc(2024, 7, 1, 0, 0)), 3_600);
+        assert_eq!(zone.dst_at(utc(2024, 1, 1, 0, 0)), 0);
+    }
+
+    #[test]
+    fn types_at_local_finds_gaps_and_folds() {
+        let zone = new_york();
+        // LMT to EST repeats the four minutes before the first transition.
+        let lmt_fold = -2_717_650_800 - 18_000 + 60;
+        assert_eq!(zone.types_at_local(lmt_fold), LocalResult::Ambiguous(TzifType::Table(0), TzifType::Table(1)));
+        assert_eq!(zone.types_at_local(utc(2007, 3, 11, 2, 30)), LocalResult::None);
+        // The last transition hands over to the footer.
+        assert_eq!(zone.types_at_local(utc(2007, 11, 4, 1, 30)), LocalResult::Ambiguous(TzifType::Table(2), TzifType::Footer(false)));
+        assert_eq!(zone.types_at_local(utc(2024, 3, 10, 2, 30)), LocalResult::None);
+        assert_eq!(
+            zone.types_at_local(utc(2024, 11, 3, 1, 30)),
+            LocalResult::Ambiguous(TzifType::Footer(true), TzifType::Footer(false))
+        );
+        assert_eq!(zone.types_at_local(utc(2024, 7, 1, 12, 0)), LocalResult::Single(TzifType::Footer(true)));
+    }
+
+    #[test]
+    fn footer_without_transitions() {
+        let zone = TzifZone::parse("EST5EDT", &tzif(2, &[], &[(-18_000, false, "EST")], NEW_YORK_RULE)).unwrap();
+        assert_eq!(zone.type_at_utc(utc(2024, 1, 1, 0, 0)), TzifType::Footer(false));
+        assert_eq!(zone.type_at_utc(utc(2024, 7, 1, 0, 0)), TzifType::Footer(true));
+        assert_eq!(zone.dst_at(utc(2024, 7, 1, 0, 0)), 3_600);
+        assert_eq!(zone.types_at_local(utc(2024, 7, 1, 12, 0)), LocalResult::Single(TzifType::Footer(true)));
+        assert_eq!(zone.types_at_local(utc(2024, 3, 10, 2, 30)), LocalResult::None);
+    }
+
+    #[test]
+    fn version_1_without_footer() {
+        let types = [(3_600, false, "CET"), (7_200, true, "CEST")];
+        let transitions = [(utc(2024, 3, 31, 1, 0), 1), (utc(2024, 10, 27, 1, 0), 0)];
+        let zone = TzifZone::parse("CET", &tzif(1, &transitions, &types, "")).unwrap();
+        assert_eq!(zone.footer, None);
+        assert_eq!(zone.type_at_utc(utc(2024, 7, 1, 0, 0)), TzifType::Table(1));
+        assert_eq!(zone.type_at_utc(utc(2030, 7, 1, 0, 0)), TzifType::Table(0));
+        assert_eq!(zone.types_at_local(utc(2030, 7, 1, 0, 0)), LocalResult::Single(TzifType::Table(0)));
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        let types = [(-18_000, false, "EST"), (-14_400, true, "EDT")];
+        let good = tzif(2, &[(0, 1)], &types, NEW_YORK_RULE);
+        assert!(TzifZone::parse("ok", &good).is_ok());
+
+        let mut bad_magic = good.clone();
+        bad_magic[0] = b'X';
+        let unordered = tzif(2, &[(10, 1), (5, 0)], &types, NEW_YORK_RULE);
+        let bad_index = tzif(2, &[(0, 2)], &types, NEW_YORK_RULE);
+        let bad_footer = tzif(2, &[(0, 1)], &types, "EST5EDT,M3");
+        let no_footer = good[..good.len() - NEW_YORK_RULE.len() - 2].to_vec();
+        for data in [&good[..40], &bad_magic, &unordered, &bad_index, &bad_footer, &no_footer] {
+            assert!(matches!(TzifZone::parse("bad", data), Err(ChronoError::InvalidTimezone(_))));
+        }
+    }
+
+    #[test]
+    fn rejects_unreadable_files() {
+        let missing = std::env::temp_dir().join("no-such-tzif-file");
+        assert!(matches!(TzifZone::from_file(&missing), Err(ChronoError::InvalidTimezone(_))));
+        assert!(matches!(TzifZone::from_file(&std::env::temp_dir()), Err(ChronoError::InvalidTimezone(_))));
+    }
+}
@@ -1,6 +1,7 @@
 use pyo3::prelude::*;
 use chrono::{Utc, Local};
 use crate::datetime::{PyDateTimeUtc, PyDateTimeLocal};
+use crate::errors::ChronoError;
 
 #[pyfunction]
 pub fn now_utc() -> PyDateTimeUtc {
@@ -16,14 +17,14 @@ pub fn now_local() -> PyDateTimeLocal {
 pub fn timestamp_millis(millis: i64) -> PyResult<PyDateTimeUtc> {
     chrono::DateTime::from_timestamp_millis(millis)
         .map(|dt| PyDateTimeUtc { inner: dt })
-        .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("Invalid timestamp"))
+        .ok_or_else(|| ChronoError::OutOfRange("Invalid timestamp".to_string()).into())
 }
 
 #[pyfunction]
 pub fn timestamp_micros(micros: i64) -> PyResult<PyDateTimeUtc> {
     chrono::DateTime::from_timestamp_micros(micros)
         .map(|dt| PyDateTimeUtc { inner: dt })
-        .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("Invalid timestamp"))
+        .ok_or_else(|| ChronoError::OutOfRange("Invalid timestamp".to_string()).into())
 }
 
 #[pyfunction]
@@ -1,5 +1,8 @@
 use pyo3::prelude::*;
 use chrono::Weekday;
+use crate::pickle;
+use crate::errors::ChronoError;
+use crate::locale;
 
 #[pyclass(name = "Weekday")]
 #[derive(Clone, Copy)]
@@ -7,6 +10,7 @@ pub struct PyWeekday {
     pub inner: Weekday,
 }
 
+pickle::immutable_pymethods! {
 #[pymethods]
 impl PyWeekday {
     #[new]
@@ -19,7 +23,7 @@ impl PyWeekday {
             4 => Weekday::Fri,
             5 => Weekday::Sat,
             6 => Weekday::Sun,
-            _ => return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("Day must be 0-6")),
+            _ => return Err(ChronoError::OutOfRange("Day must be 0-6".to_string()).into()),
         };
         Ok(PyWeekday { inner: weekday })
     }
@@ -83,6 +87,23 @@ impl PyWeekday {
         PyWeekday { inner: self.inner.pred() }
     }
 
+    /// The full name, in English or in `locale` (such as `"de_DE"`, which
+    /// needs the `locales` feature).
+    #[pyo3(signature = (locale=None))]
+    fn name(&self, locale: Option<&str>) -> PyResult<String> {
+        locale::weekday_name(self.inner, locale, false)
+    }
+
+    /// The abbreviated name, in English or in `locale`.
+    #[pyo3(signature = (locale=None))]
+    fn short_name(&self, locale: Option<&str>) -> PyResult<String> {
+        locale::weekday_name(self.inner, locale, true)
+    }
+
+    fn __reduce__(&self, py: Python) -> PyResult<(PyObject, (&'static str, u8, PyObject))> {
+        pickle::reduce(py, "Weekday", (self.inner.num_days_from_monday(),))
+    }
+
     fn __str__(&self) -> String {
         format!("{:?}", self.inner)
     }
@@ -107,3 +128,4 @@ impl PyWeekday {
         self.inner.number_from_monday() as u64
     }
 }
+}